@@ -179,7 +179,8 @@ impl OrbitalInstance {
         Ok(response)
     }
 
-    /// Get the attributes of the NFT
+    /// Get the attributes of the NFT, as returned by the collection
+    /// (staking metadata included when the collection is the stake vault)
     /// Opcode: 1002
     fn get_attributes(&self) -> Result<CallResponse> {
         let context = self.context()?;
@@ -0,0 +1,33 @@
+use metashrew_support::index_pointer::KeyValuePointer;
+
+/// The storage claims and vesting releases write to: the $BB supply and claim
+/// totals, the amount claimed by each NFT, the vesting duration and positions,
+/// and the event log
+pub struct ClaimStorage<P: KeyValuePointer> {
+    pub bb_supply: P,
+    pub total_claimed: P,
+    pub claimed_amounts: P,
+    pub vesting_duration: P,
+    pub vesting_positions: P,
+    pub vesting_positions_by_nft: P,
+    pub events: P,
+}
+
+#[cfg(test)]
+impl ClaimStorage<orbitals_common::memory_pointer::MemoryPointer> {
+    /// Claim storage held in memory under `prefix`, so tests don't share it
+    pub fn in_memory(prefix: &str) -> Self {
+        use orbitals_common::memory_pointer::MemoryPointer;
+
+        let pointer = |name: &str| MemoryPointer::from_keyword(&format!("{}/{}", prefix, name));
+        Self {
+            bb_supply: pointer("bb-supply"),
+            total_claimed: pointer("total-claimed"),
+            claimed_amounts: pointer("claimed-amounts"),
+            vesting_duration: pointer("vesting-duration"),
+            vesting_positions: pointer("vesting-positions"),
+            vesting_positions_by_nft: pointer("vesting-positions-by-nft"),
+            events: pointer("events"),
+        }
+    }
+}
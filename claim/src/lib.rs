@@ -9,10 +9,14 @@ use metashrew_support::compat::to_arraybuffer_layout;
 use metashrew_support::index_pointer::KeyValuePointer;
 
 use anyhow::{anyhow, Result};
+use orbitals_common::eligibility::EligibilityProof;
+use orbitals_common::events::{self, Event, EventKind};
 use std::sync::Arc;
 
-mod orbitals_ids;
-use orbitals_ids::BEEP_BOOP_IDS;
+mod claim_storage;
+#[cfg(test)]
+mod tests;
+use claim_storage::ClaimStorage;
 
 pub const BB_IMAGE: &[u8] = include_bytes!("./bb.png");
 
@@ -27,15 +31,29 @@ const MAX_CLAIM_PER_NFT: u128 = 2_500_000_000_000;
 // Min staked blocks to actually swap the beep boop to bb
 const MIN_STAKED_BLOCKS: u128 = 25_000;
 
-// Reward scaling factor (8 decimal places)
+// Reward scaling factor (8 decimal places), the rate of the genesis emission epoch
 const REWARD_SCALE: u128 = 100_000_000;
 // Token supply constants
 const MAX_SUPPLY: u128 = 25_000_000_000_000_000;
 
 // Stake contract opcodes that we need to call
 const STAKE_GET_STAKED_HEIGHT: u128 = 507;
-const STAKE_GET_STAKED_BY_LP: u128 = 508;
+const STAKE_GET_STAKED_BY_LP_BINARY: u128 = 518;
 const STAKE_GET_TOTAL_STAKED_BLOCKS: u128 = 510;
+const STAKE_GET_ORBITAL_COLLECTION: u128 = 608;
+const STAKE_GET_ELIGIBLE_ROOT: u128 = 609;
+const STAKE_GET_STAKE_SESSIONS: u128 = 513;
+
+// Sessions returned by one GetStakeSessions page, and the size of each one
+const STAKE_SESSIONS_PAGE_SIZE: u128 = 50;
+const STAKE_SESSION_SIZE: usize = 64;
+
+// NFT id + total + start height + duration + released
+const VESTING_POSITION_SIZE: usize = 96;
+// index + stored position + vested + unvested, as returned by the views
+const VESTING_ENTRY_SIZE: usize = 144;
+// Positions returned by one GetVestingPositionsByAlkaneId page
+const VESTING_POSITIONS_PAGE_SIZE: u128 = 50;
 
 #[derive(Default)]
 pub struct BB(());
@@ -45,9 +63,10 @@ impl AlkaneResponder for BB {}
 /// Message types for claim contract interaction
 #[derive(MessageDispatch)]
 enum BBMessage {
-    /// Initialize the claim contract
+    /// Initialize the claim contract against a stake contract, which must be
+    /// given explicitly (0:0 is rejected)
     #[opcode(0)]
-    Initialize,
+    Initialize { stake_block: u128, stake_tx: u128 },
 
     /// Get the name of the claim contract
     #[opcode(99)]
@@ -95,7 +114,12 @@ enum BBMessage {
     GetTotalAvailableToClaim { block: u128, tx: u128 },
 
     /// BB available rewards for specific alkane IDs
+    ///
+    /// Originals not yet verified by the stake contract need an eligibility proof
+    /// after `amount`. Returns the amount claimed, which is less than `amount`
+    /// when the remaining $BB supply can't cover it
     #[opcode(400)]
+    #[returns(u128)]
     ClaimRewards { amount: u128 },
 
     /// Get total amount claimed across all alkanes
@@ -103,11 +127,57 @@ enum BBMessage {
     #[returns(u128)]
     GetTotalClaimed,
 
+    /// Claim every available reward of the NFTs and LP tokens sent, up to
+    /// MAX_CLAIM_PER_NFT each
+    ///
+    /// Eligibility proofs follow the opcode in the inputs. Returns the total
+    /// claimed, the part transferred now and the part put into vesting positions
+    /// (u128 each), followed by one entry per original NFT: NFT id (block u128,
+    /// tx u128) and amount claimed (u128), all little-endian
+    #[opcode(402)]
+    #[returns(Vec<u8>)]
+    ClaimAll,
+
+    /// Get the $BB that can still be minted before reaching the max supply
+    #[opcode(403)]
+    #[returns(u128)]
+    GetRemainingBudget,
+
+    /// Pay out the unlocked part of a vesting position, the NFT or one of its LP
+    /// tokens must be sent along
+    ///
+    /// A position belongs to whoever holds its NFT, not to the claimant: what
+    /// has vested and not been released goes to the holder at release time
+    #[opcode(404)]
+    ReleaseVested { index: u128 },
+
+    /// Get a vesting position as 144 bytes: index, NFT id (block, tx), total,
+    /// start height, duration, released, vested and unvested, all little-endian
+    /// u128 values
+    #[opcode(405)]
+    #[returns(Vec<u8>)]
+    GetVestingPosition { index: u128 },
+
+    /// Get a page of the vesting positions of an NFT
+    ///
+    /// Returns the position count (u128) followed by up to `limit` (at most 50)
+    /// 144-byte entries starting at `offset`, laid out as in GetVestingPosition
+    #[opcode(406)]
+    #[returns(Vec<u8>)]
+    GetVestingPositionsByAlkaneId {
+        block: u128,
+        tx: u128,
+        offset: u128,
+        limit: u128,
+    },
+
     /// Swap $BB tokens to BEEP BOOPs (25K $BB -> 1 BEEP BOOP)
     #[opcode(501)]
     SwapBBToBeepBoop,
 
     /// Swap BEEP BOOPs to $BB tokens (1 BEEP BOOP -> 25K $BB)
+    ///
+    /// Originals not yet verified by the stake contract need an eligibility proof
     #[opcode(502)]
     SwapBeepBoopToBB,
 
@@ -118,6 +188,8 @@ enum BBMessage {
 
     // GetSwapRate,
     /// Deposit BEEP BOOP tokens to contract for swapping
+    ///
+    /// Originals not yet verified by the stake contract need an eligibility proof
     #[opcode(511)]
     DepositBeepBoop,
 
@@ -146,6 +218,63 @@ enum BBMessage {
     #[returns(u128)]
     GetUsedSwapIndex,
 
+    /// Get the alkane ID of a stored BEEP BOOP token by index as 32 bytes
+    #[opcode(517)]
+    #[returns(Vec<u8>)]
+    GetStoredBeepBoopAlkaneIdBinary { index: u128 },
+
+    /// Get the alkane ID of a used BEEP BOOP token by index as 32 bytes
+    #[opcode(518)]
+    #[returns(Vec<u8>)]
+    GetUsedBeepBoopAlkaneIdBinary { index: u128 },
+
+    /// Get a range of the event log
+    ///
+    /// Returns the event count (u128) followed by up to `limit` events starting at
+    /// sequence `start`, 128 bytes each: sequence, kind, height, NFT id,
+    /// counterpart id ($BB or depositor) and amount, all little-endian u128 values
+    #[opcode(519)]
+    #[returns(Vec<u8>)]
+    GetEvents { start: u128, limit: u128 },
+
+    /// Register another stake contract whose staked blocks add up to the rewards,
+    /// requires the admin token of the primary stake contract
+    #[opcode(600)]
+    AddStakeContract { block: u128, tx: u128 },
+
+    /// Get the registered stake contracts as concatenated ids (block u128, tx u128),
+    /// the primary one first
+    #[opcode(601)]
+    #[returns(Vec<u8>)]
+    GetStakeContracts,
+
+    /// Get the primary stake contract id as 32 bytes (block u128, tx u128)
+    #[opcode(602)]
+    #[returns(Vec<u8>)]
+    GetStakeContractIdBinary,
+
+    /// Start a new emission epoch paying `rate` $BB per staked block from
+    /// `start_height`, requires the admin token of the primary stake contract.
+    /// The start can't be before the current block
+    #[opcode(603)]
+    AddEmissionEpoch { start_height: u128, rate: u128 },
+
+    /// Get the emission schedule as (start height u128, rate u128) pairs, starting
+    /// with the genesis epoch (0, 10^8)
+    #[opcode(604)]
+    #[returns(Vec<u8>)]
+    GetEmissionSchedule,
+
+    /// Set the number of blocks claimed $BB vests over, 0 pays claims right away,
+    /// requires the admin token of the primary stake contract
+    #[opcode(605)]
+    SetVestingDuration { blocks: u128 },
+
+    /// Get the number of blocks claimed $BB vests over
+    #[opcode(606)]
+    #[returns(u128)]
+    GetVestingDuration,
+
     /// Get data - default alkanes opcode for data retrieval
     #[opcode(1000)]
     #[returns(Vec<u8>)]
@@ -163,13 +292,217 @@ impl Token for BB {
 }
 
 impl BB {
-    pub fn initialize(&self) -> Result<CallResponse> {
+    pub fn initialize(&self, stake_block: u128, stake_tx: u128) -> Result<CallResponse> {
         self.observe_initialization()?;
         let context = self.context()?;
         let response = CallResponse::forward(&context.incoming_alkanes);
+
+        let stake_contract_id = self.initial_stake_contract_id(stake_block, stake_tx)?;
+        self.register_stake_contract(&stake_contract_id)?;
+
+        Ok(response)
+    }
+
+    /// Register another stake contract (admin only)
+    pub fn add_stake_contract(&self, block: u128, tx: u128) -> Result<CallResponse> {
+        self.only_admin()?;
+
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.register_stake_contract(&AlkaneId { block, tx })?;
+
+        Ok(response)
+    }
+
+    pub fn get_stake_contracts(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let mut data = Vec::new();
+        for stake_contract_id in self.get_stake_contract_ids() {
+            data.extend_from_slice(&self.alkane_id_to_bytes(&stake_contract_id));
+        }
+
+        response.data = data;
+        Ok(response)
+    }
+
+    pub fn get_stake_contract_id_binary(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.alkane_id_to_bytes(&self.get_stake_contract_id());
+        Ok(response)
+    }
+
+    /// Append an epoch to the emission schedule (admin only)
+    pub fn add_emission_epoch(&self, start_height: u128, rate: u128) -> Result<CallResponse> {
+        self.only_admin()?;
+
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        let (last_start_height, _) = *self.emission_epochs().last().unwrap();
+        self.check_epoch_start(start_height, last_start_height, u128::from(self.height()))?;
+
+        let mut data = Vec::with_capacity(32);
+        data.extend_from_slice(&start_height.to_le_bytes());
+        data.extend_from_slice(&rate.to_le_bytes());
+
+        let mut epochs_pointer = self.emission_epochs_pointer();
+        let count = epochs_pointer.get_value::<u128>();
+        epochs_pointer
+            .select(&count.to_le_bytes().to_vec())
+            .set(Arc::new(data));
+        epochs_pointer.set_value(count + 1);
+
         Ok(response)
     }
 
+    pub fn get_emission_schedule(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let mut data = Vec::new();
+        for (start_height, rate) in self.emission_epochs() {
+            data.extend_from_slice(&start_height.to_le_bytes());
+            data.extend_from_slice(&rate.to_le_bytes());
+        }
+
+        response.data = data;
+        Ok(response)
+    }
+
+    /// Fail unless a new epoch starts after the last one and not before the
+    /// current block, so rewards already accrued are never repriced
+    fn check_epoch_start(
+        &self,
+        start_height: u128,
+        last_start_height: u128,
+        height: u128,
+    ) -> Result<()> {
+        if start_height <= last_start_height {
+            return Err(anyhow!(
+                "Epoch must start after block {}",
+                last_start_height
+            ));
+        }
+
+        if start_height < height {
+            return Err(anyhow!(
+                "Epoch can't start before the current block {}",
+                height
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Get the emission epochs as (start height, rate), the genesis epoch first
+    fn emission_epochs(&self) -> Vec<(u128, u128)> {
+        let epochs_pointer = self.emission_epochs_pointer();
+        let count = epochs_pointer.get_value::<u128>();
+
+        let mut epochs = vec![(0u128, REWARD_SCALE)];
+        for i in 0..count {
+            let epoch = epochs_pointer.select(&i.to_le_bytes().to_vec()).get();
+            if epoch.len() == 32 {
+                epochs.push((
+                    u128::from_le_bytes(epoch[0..16].try_into().unwrap()),
+                    u128::from_le_bytes(epoch[16..32].try_into().unwrap()),
+                ));
+            }
+        }
+
+        epochs
+    }
+
+    /// Sum the rewards of the blocks in [start, end) across the epochs they overlap
+    fn emission_between(&self, epochs: &[(u128, u128)], start: u128, end: u128) -> u128 {
+        let mut rewards = 0u128;
+
+        for (i, (epoch_start, rate)) in epochs.iter().enumerate() {
+            let epoch_end = epochs.get(i + 1).map_or(u128::MAX, |(next, _)| *next);
+            let overlap_start = start.max(*epoch_start);
+            let overlap_end = end.min(epoch_end);
+
+            if overlap_end > overlap_start {
+                rewards =
+                    rewards.saturating_add((overlap_end - overlap_start).saturating_mul(*rate));
+            }
+        }
+
+        rewards
+    }
+
+    /// Get the stake contract to register at initialization, failing on 0:0 so
+    /// a deploy that omits the id doesn't silently bind to the mainnet vault
+    fn initial_stake_contract_id(&self, stake_block: u128, stake_tx: u128) -> Result<AlkaneId> {
+        if stake_block == 0 && stake_tx == 0 {
+            return Err(anyhow!(
+                "Initialize needs the stake contract id as block, tx"
+            ));
+        }
+
+        Ok(AlkaneId {
+            block: stake_block,
+            tx: stake_tx,
+        })
+    }
+
+    fn register_stake_contract(&self, stake_contract_id: &AlkaneId) -> Result<()> {
+        self.push_stake_contract(&mut self.stake_contracts_pointer(), stake_contract_id)
+    }
+
+    /// Append a stake contract to the list at `stake_contracts`, rejecting duplicates
+    fn push_stake_contract<P: KeyValuePointer>(
+        &self,
+        stake_contracts: &mut P,
+        stake_contract_id: &AlkaneId,
+    ) -> Result<()> {
+        if self
+            .read_stake_contract_ids(stake_contracts)
+            .contains(stake_contract_id)
+        {
+            return Err(anyhow!(
+                "Stake contract {}:{} already registered",
+                stake_contract_id.block,
+                stake_contract_id.tx
+            ));
+        }
+
+        let count = stake_contracts.get_value::<u128>();
+        stake_contracts
+            .select(&count.to_le_bytes().to_vec())
+            .set(Arc::new(self.alkane_id_to_bytes(stake_contract_id)));
+        stake_contracts.set_value(count + 1);
+
+        Ok(())
+    }
+
+    /// Ensure the call carries the admin token of the primary stake contract
+    fn only_admin(&self) -> Result<()> {
+        let context = self.context()?;
+        let stake_contract_id = self.get_stake_contract_id();
+
+        let authorized = context
+            .incoming_alkanes
+            .0
+            .iter()
+            .any(|alkane| alkane.id == stake_contract_id && alkane.value >= 1);
+
+        if !authorized {
+            return Err(anyhow!(
+                "Call must carry the admin token of stake contract {}:{}",
+                stake_contract_id.block,
+                stake_contract_id.tx
+            ));
+        }
+
+        Ok(())
+    }
+
     pub fn get_name(&self) -> Result<CallResponse> {
         let context = self.context()?;
         let mut response = CallResponse::forward(&context.incoming_alkanes);
@@ -217,7 +550,7 @@ impl BB {
         let mut response = CallResponse::forward(&context.incoming_alkanes);
 
         let alkane_id = AlkaneId { block, tx };
-        let original_nft_id = if self.is_original(&alkane_id)? {
+        let original_nft_id = if self.is_original(&alkane_id, &[])? {
             alkane_id
         } else {
             self.get_original_nft_from_lp(&alkane_id)?
@@ -233,7 +566,7 @@ impl BB {
         let mut response = CallResponse::forward(&context.incoming_alkanes);
 
         let alkane_id = AlkaneId { block, tx };
-        let original_nft_id = if self.is_original(&alkane_id)? {
+        let original_nft_id = if self.is_original(&alkane_id, &[])? {
             alkane_id
         } else {
             self.get_original_nft_from_lp(&alkane_id)?
@@ -252,7 +585,7 @@ impl BB {
         let mut response = CallResponse::forward(&context.incoming_alkanes);
 
         let alkane_id = AlkaneId { block, tx };
-        let is_original = self.is_original(&alkane_id)?;
+        let is_original = self.is_original(&alkane_id, &[])?;
         let original_id = if is_original {
             alkane_id
         } else {
@@ -266,9 +599,7 @@ impl BB {
             .select(&self.alkane_id_to_bytes(&original_id))
             .get_value::<u128>();
 
-        let earned_available = total_rewards.saturating_sub(claimed_amount);
-        let remaining_lifetime_limit = MAX_CLAIM_PER_NFT.saturating_sub(claimed_amount);
-        let available = earned_available.min(remaining_lifetime_limit);
+        let available = self.claimable(total_rewards, claimed_amount);
         response.data = available.to_le_bytes().to_vec();
 
         Ok(response)
@@ -277,6 +608,9 @@ impl BB {
     pub fn claim_rewards(&self, amount: u128) -> Result<CallResponse> {
         let context = self.context()?;
 
+        // inputs: opcode, amount, then eligibility proofs
+        let proofs = EligibilityProof::parse_all(context.inputs.get(2..).unwrap_or_default())?;
+
         if context.incoming_alkanes.0.is_empty() {
             return Err(anyhow!("Must provide alkane IDs to claim rewards for"));
         }
@@ -285,73 +619,528 @@ impl BB {
             return Err(anyhow!("Amount must be greater than 0"));
         }
 
-        let mut total_claimed = 0u128;
-        let mut remaining_to_claim = amount;
+        // At the supply cap the claim is partially filled with what is left
+        let target = self.claim_target(amount, self.remaining_budget())?;
+        let claims = self.plan_claims(&proofs, target)?;
+        let mut storage = self.claim_storage();
+        let height = u128::from(self.height());
+        let total_claimed = self.commit_claims(&mut storage, &claims, &context.myself, height)?;
+
+        // Check if we were able to claim the full requested amount
+        if total_claimed < target {
+            return Err(anyhow!(
+                "Insufficient rewards available: requested {}, could only claim {}",
+                amount,
+                total_claimed
+            ));
+        }
+
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+        self.pay_claims(
+            &mut storage,
+            &mut response,
+            &claims,
+            total_claimed,
+            &context.myself,
+            height,
+        )?;
+        response.data = total_claimed.to_le_bytes().to_vec();
+
+        Ok(response)
+    }
+
+    pub fn claim_all(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+
+        // inputs: opcode, then eligibility proofs
+        let proofs = EligibilityProof::parse_all(context.inputs.get(1..).unwrap_or_default())?;
+
+        if context.incoming_alkanes.0.is_empty() {
+            return Err(anyhow!("Must provide alkane IDs to claim rewards for"));
+        }
+
+        let target = self.claim_target(u128::MAX, self.remaining_budget())?;
+        let claims = self.plan_claims(&proofs, target)?;
+        let mut storage = self.claim_storage();
+        let height = u128::from(self.height());
+        let total_claimed = self.commit_claims(&mut storage, &claims, &context.myself, height)?;
+
+        if total_claimed == 0 {
+            return Err(anyhow!("No rewards available to claim"));
+        }
+
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+        let transferred = self.pay_claims(
+            &mut storage,
+            &mut response,
+            &claims,
+            total_claimed,
+            &context.myself,
+            height,
+        )?;
+        response.data = self.encode_claim_breakdown(total_claimed, transferred, &claims);
+
+        Ok(response)
+    }
+
+    /// Work out the claims of the NFTs and LP tokens sent, in order, until `limit`
+    /// is reached. Returns the original NFT id, amount previously claimed and
+    /// amount to claim of each NFT with something to claim. Nothing is written.
+    fn plan_claims(
+        &self,
+        proofs: &[EligibilityProof],
+        limit: u128,
+    ) -> Result<Vec<(AlkaneId, u128, u128)>> {
+        let context = self.context()?;
+
+        let mut claims: Vec<(AlkaneId, u128, u128)> = Vec::new();
+        let mut remaining_to_claim = limit;
 
         for alkane in &context.incoming_alkanes.0 {
             if remaining_to_claim == 0 {
-                break; // We've claimed the requested amount
+                break; // We've planned the requested amount
             }
 
-            let is_original = self.is_original(&alkane.id)?;
+            let is_original = self.is_original(&alkane.id, proofs)?;
             let original_nft_id = if is_original {
                 alkane.id
             } else {
                 self.get_original_nft_from_lp(&alkane.id)?
             };
-            let total_rewards = self.calculate_total_rewards(&original_nft_id, is_original)?;
-
-            let original_nft_bytes = self.alkane_id_to_bytes(&original_nft_id);
-            let mut claimed_pointer = self.claimed_amounts_pointer().select(&original_nft_bytes);
-            let previously_claimed = claimed_pointer.get_value::<u128>();
 
-            if total_rewards <= previously_claimed {
-                continue; // Skip this alkane, no rewards available
+            if claims.iter().any(|(id, _, _)| *id == original_nft_id) {
+                continue;
             }
 
-            let earned_available = total_rewards.saturating_sub(previously_claimed);
-            let remaining_lifetime_limit = MAX_CLAIM_PER_NFT.saturating_sub(previously_claimed);
-            let available = earned_available.min(remaining_lifetime_limit);
+            let total_rewards = self.calculate_total_rewards(&original_nft_id, is_original)?;
+            let previously_claimed = self
+                .claimed_amounts_pointer()
+                .select(&self.alkane_id_to_bytes(&original_nft_id))
+                .get_value::<u128>();
+            let available = self.claimable(total_rewards, previously_claimed);
 
             if available > 0 {
                 let to_claim_from_this = remaining_to_claim.min(available);
-                let new_total_claimed = previously_claimed + to_claim_from_this;
-                claimed_pointer.set_value(new_total_claimed);
-                total_claimed += to_claim_from_this;
+                claims.push((original_nft_id, previously_claimed, to_claim_from_this));
                 remaining_to_claim -= to_claim_from_this;
             }
         }
 
-        // Check if we were able to claim the full requested amount
-        if remaining_to_claim > 0 {
+        Ok(claims)
+    }
+
+    /// Rewards an NFT can still claim: what it earned beyond what it claimed,
+    /// capped by what is left of MAX_CLAIM_PER_NFT
+    fn claimable(&self, total_rewards: u128, previously_claimed: u128) -> u128 {
+        let earned_available = total_rewards.saturating_sub(previously_claimed);
+        let remaining_lifetime_limit = MAX_CLAIM_PER_NFT.saturating_sub(previously_claimed);
+        earned_available.min(remaining_lifetime_limit)
+    }
+
+    /// Encode the ClaimAll response: total claimed, transferred and vesting, then
+    /// the NFT id and amount of each claim
+    fn encode_claim_breakdown(
+        &self,
+        total_claimed: u128,
+        transferred: u128,
+        claims: &[(AlkaneId, u128, u128)],
+    ) -> Vec<u8> {
+        let mut data = Vec::with_capacity(48 + claims.len() * 48);
+        data.extend_from_slice(&total_claimed.to_le_bytes());
+        data.extend_from_slice(&transferred.to_le_bytes());
+        data.extend_from_slice(&(total_claimed - transferred).to_le_bytes());
+        for (original_nft_id, _, amount) in claims {
+            data.extend_from_slice(&self.alkane_id_to_bytes(original_nft_id));
+            data.extend_from_slice(&amount.to_le_bytes());
+        }
+        data
+    }
+
+    /// Record planned claims and mint them into the $BB supply. Returns the
+    /// total claimed.
+    fn commit_claims<P: KeyValuePointer>(
+        &self,
+        storage: &mut ClaimStorage<P>,
+        claims: &[(AlkaneId, u128, u128)],
+        bb_id: &AlkaneId,
+        height: u128,
+    ) -> Result<u128> {
+        let total_claimed = claims
+            .iter()
+            .try_fold(0u128, |total, (_, _, amount)| total.checked_add(*amount))
+            .ok_or_else(|| anyhow!("Total claimed overflow"))?;
+
+        // plan_claims was limited to the remaining budget
+        let current_bb_supply = storage.bb_supply.get_value::<u128>();
+        debug_assert!(total_claimed <= self.budget_left(current_bb_supply));
+
+        storage
+            .bb_supply
+            .set_value(current_bb_supply + total_claimed);
+
+        let current_total = storage.total_claimed.get_value::<u128>();
+        storage
+            .total_claimed
+            .set_value(current_total + total_claimed);
+
+        for (original_nft_id, previously_claimed, amount) in claims {
+            storage
+                .claimed_amounts
+                .select(&self.alkane_id_to_bytes(original_nft_id))
+                .set_value(previously_claimed + amount);
+
+            events::append_event(
+                &mut storage.events,
+                &Event {
+                    kind: EventKind::RewardsClaimed,
+                    height,
+                    subject: *original_nft_id,
+                    counterpart: *bb_id,
+                    amount: *amount,
+                },
+            )?;
+        }
+
+        Ok(total_claimed)
+    }
+
+    /// Transfer committed claims, or record a vesting position per NFT when a
+    /// vesting duration is set. Returns the amount transferred
+    fn pay_claims<P: KeyValuePointer>(
+        &self,
+        storage: &mut ClaimStorage<P>,
+        response: &mut CallResponse,
+        claims: &[(AlkaneId, u128, u128)],
+        total_claimed: u128,
+        bb_id: &AlkaneId,
+        height: u128,
+    ) -> Result<u128> {
+        let duration = storage.vesting_duration.get_value::<u128>();
+
+        if duration == 0 {
+            response.alkanes.0.push(AlkaneTransfer {
+                id: *bb_id,
+                value: total_claimed,
+            });
+            return Ok(total_claimed);
+        }
+
+        for (original_nft_id, _, amount) in claims {
+            self.add_vesting_position(storage, original_nft_id, *amount, height, duration)?;
+        }
+
+        Ok(0)
+    }
+
+    pub fn release_vested(&self, index: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let held_nft_ids = self.held_nft_ids(&context.incoming_alkanes.0);
+        let releasable = self.release_position(
+            &mut self.claim_storage(),
+            index,
+            &held_nft_ids,
+            &context.myself,
+            u128::from(self.height()),
+        )?;
+
+        response.alkanes.0.push(AlkaneTransfer {
+            id: context.myself,
+            value: releasable,
+        });
+
+        Ok(response)
+    }
+
+    /// Release what vested of a position at `height`, if its NFT is among the
+    /// `held_nft_ids`, whoever holds it now. Returns the amount released
+    fn release_position<P: KeyValuePointer>(
+        &self,
+        storage: &mut ClaimStorage<P>,
+        index: u128,
+        held_nft_ids: &[AlkaneId],
+        bb_id: &AlkaneId,
+        height: u128,
+    ) -> Result<u128> {
+        let (nft_id, total, start_height, duration, released) =
+            self.get_vesting_position_entry(&storage.vesting_positions, index)?;
+
+        if !held_nft_ids.contains(&nft_id) {
             return Err(anyhow!(
-                "Insufficient rewards available: requested {}, could only claim {}",
-                amount,
-                total_claimed
+                "Must send NFT {}:{} or one of its LP tokens to release position {}",
+                nft_id.block,
+                nft_id.tx,
+                index
             ));
         }
 
+        let releasable =
+            self.releasable_amount(index, total, start_height, duration, released, height)?;
+
+        self.set_vesting_position_entry(
+            &storage.vesting_positions,
+            index,
+            &(nft_id, total, start_height, duration, released + releasable),
+        );
+
+        events::append_event(
+            &mut storage.events,
+            &Event {
+                kind: EventKind::VestingReleased,
+                height,
+                subject: nft_id,
+                counterpart: *bb_id,
+                amount: releasable,
+            },
+        )?;
+
+        Ok(releasable)
+    }
+
+    /// The NFTs the alkanes sent stand for: each id itself, and the original
+    /// NFT of each one that is an LP token
+    fn held_nft_ids(&self, alkanes: &[AlkaneTransfer]) -> Vec<AlkaneId> {
+        alkanes
+            .iter()
+            .flat_map(|alkane| {
+                std::iter::once(alkane.id).chain(self.get_original_nft_from_lp(&alkane.id).ok())
+            })
+            .collect()
+    }
+
+    pub fn get_vesting_position(&self, index: u128) -> Result<CallResponse> {
+        let context = self.context()?;
         let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-        if total_claimed > 0 {
-            let mut total_claimed_pointer = self.total_claimed_pointer();
-            let current_total = total_claimed_pointer.get_value::<u128>();
-            total_claimed_pointer.set_value(current_total + total_claimed);
+        response.data = self.encode_vesting_position(index)?;
 
-            let current_bb_supply = self.bb_supply_pointer().get_value::<u128>();
-            if current_bb_supply + total_claimed > MAX_SUPPLY {
-                return Err(anyhow!("Would exceed max BB supply"));
-            }
+        Ok(response)
+    }
+
+    pub fn get_vesting_positions_by_alkane_id(
+        &self,
+        block: u128,
+        tx: u128,
+        offset: u128,
+        limit: u128,
+    ) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-            self.bb_supply_pointer()
-                .set_value(current_bb_supply + total_claimed);
+        let nft_positions_pointer = self
+            .vesting_positions_by_nft_pointer()
+            .select(&self.alkane_id_to_bytes(&AlkaneId { block, tx }));
+        let count = nft_positions_pointer.get_value::<u128>();
+        let (start, end) = self.vesting_page(offset, limit, count);
+
+        let mut data = Vec::with_capacity(16 + (end - start) as usize * VESTING_ENTRY_SIZE);
+        data.extend_from_slice(&count.to_le_bytes());
+        for i in start..end {
+            let index = nft_positions_pointer
+                .select(&i.to_le_bytes().to_vec())
+                .get_value::<u128>();
+            data.extend_from_slice(&self.encode_vesting_position(index)?);
+        }
 
-            response.alkanes.0.push(AlkaneTransfer {
-                id: context.myself.clone(),
-                value: total_claimed,
-            });
+        response.data = data;
+        Ok(response)
+    }
+
+    pub fn set_vesting_duration(&self, blocks: u128) -> Result<CallResponse> {
+        self.only_admin()?;
+
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.vesting_duration_pointer().set_value(blocks);
+
+        Ok(response)
+    }
+
+    pub fn get_vesting_duration(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self
+            .vesting_duration_pointer()
+            .get_value::<u128>()
+            .to_le_bytes()
+            .to_vec();
+
+        Ok(response)
+    }
+
+    /// Append a vesting position and index it under its NFT
+    fn add_vesting_position<P: KeyValuePointer>(
+        &self,
+        storage: &mut ClaimStorage<P>,
+        nft_id: &AlkaneId,
+        total: u128,
+        start_height: u128,
+        duration: u128,
+    ) -> Result<u128> {
+        let index = storage.vesting_positions.get_value::<u128>();
+
+        self.set_vesting_position_entry(
+            &storage.vesting_positions,
+            index,
+            &(*nft_id, total, start_height, duration, 0),
+        );
+        storage.vesting_positions.set_value(index + 1);
+
+        let mut nft_positions_pointer = storage
+            .vesting_positions_by_nft
+            .select(&self.alkane_id_to_bytes(nft_id));
+        let nft_count = nft_positions_pointer.get_value::<u128>();
+        nft_positions_pointer
+            .select(&nft_count.to_le_bytes().to_vec())
+            .set_value(index);
+        nft_positions_pointer.set_value(nft_count + 1);
+
+        Ok(index)
+    }
+
+    /// Store a vesting position as (NFT id, total, start height, duration, released)
+    fn set_vesting_position_entry<P: KeyValuePointer>(
+        &self,
+        positions: &P,
+        index: u128,
+        position: &(AlkaneId, u128, u128, u128, u128),
+    ) {
+        let (nft_id, total, start_height, duration, released) = *position;
+
+        let mut data = Vec::with_capacity(VESTING_POSITION_SIZE);
+        data.extend_from_slice(&self.alkane_id_to_bytes(&nft_id));
+        data.extend_from_slice(&total.to_le_bytes());
+        data.extend_from_slice(&start_height.to_le_bytes());
+        data.extend_from_slice(&duration.to_le_bytes());
+        data.extend_from_slice(&released.to_le_bytes());
+
+        positions
+            .select(&index.to_le_bytes().to_vec())
+            .set(Arc::new(data));
+    }
+
+    /// Get a vesting position as (NFT id, total, start height, duration, released)
+    fn get_vesting_position_entry<P: KeyValuePointer>(
+        &self,
+        positions: &P,
+        index: u128,
+    ) -> Result<(AlkaneId, u128, u128, u128, u128)> {
+        let data = positions.select(&index.to_le_bytes().to_vec()).get();
+
+        if data.len() != VESTING_POSITION_SIZE {
+            return Err(anyhow!("No vesting position at index {}", index));
         }
 
+        let value_at =
+            |offset: usize| u128::from_le_bytes(data[offset..offset + 16].try_into().unwrap());
+
+        Ok((
+            self.bytes_to_nft_id(&data[0..32])?,
+            value_at(32),
+            value_at(48),
+            value_at(64),
+            value_at(80),
+        ))
+    }
+
+    /// Clamp a page of an NFT's vesting positions to [start, end), at most
+    /// VESTING_POSITIONS_PAGE_SIZE of them
+    fn vesting_page(&self, offset: u128, limit: u128, count: u128) -> (u128, u128) {
+        let end = offset
+            .saturating_add(limit.min(VESTING_POSITIONS_PAGE_SIZE))
+            .min(count);
+        (offset.min(end), end)
+    }
+
+    fn encode_vesting_position(&self, index: u128) -> Result<Vec<u8>> {
+        let position = self.get_vesting_position_entry(&self.vesting_positions_pointer(), index)?;
+
+        Ok(self.encode_vesting_entry(index, &position, u128::from(self.height())))
+    }
+
+    /// Encode a vesting position as (NFT id, total, start height, duration,
+    /// released) seen at `height`
+    fn encode_vesting_entry(
+        &self,
+        index: u128,
+        position: &(AlkaneId, u128, u128, u128, u128),
+        height: u128,
+    ) -> Vec<u8> {
+        let (nft_id, total, start_height, duration, released) = *position;
+        let vested = self.vested_amount(total, start_height, duration, height);
+
+        let mut data = Vec::with_capacity(VESTING_ENTRY_SIZE);
+        data.extend_from_slice(&index.to_le_bytes());
+        data.extend_from_slice(&self.alkane_id_to_bytes(&nft_id));
+        data.extend_from_slice(&total.to_le_bytes());
+        data.extend_from_slice(&start_height.to_le_bytes());
+        data.extend_from_slice(&duration.to_le_bytes());
+        data.extend_from_slice(&released.to_le_bytes());
+        data.extend_from_slice(&vested.to_le_bytes());
+        data.extend_from_slice(&(total - vested).to_le_bytes());
+
+        data
+    }
+
+    /// Amount of a position unlocked at `height`, growing linearly from the start height
+    fn vested_amount(&self, total: u128, start_height: u128, duration: u128, height: u128) -> u128 {
+        let elapsed = height.saturating_sub(start_height);
+        if duration == 0 || elapsed >= duration {
+            return total;
+        }
+
+        total.saturating_mul(elapsed) / duration
+    }
+
+    /// Amount of a position that can be released at `height`, failing when
+    /// nothing unlocked since the last release
+    fn releasable_amount(
+        &self,
+        index: u128,
+        total: u128,
+        start_height: u128,
+        duration: u128,
+        released: u128,
+        height: u128,
+    ) -> Result<u128> {
+        let vested = self.vested_amount(total, start_height, duration, height);
+        let releasable = vested.saturating_sub(released);
+        if releasable == 0 {
+            return Err(anyhow!("Nothing vested to release for position {}", index));
+        }
+
+        Ok(releasable)
+    }
+
+    /// $BB that can still be minted before reaching MAX_SUPPLY
+    fn remaining_budget(&self) -> u128 {
+        self.budget_left(self.bb_supply_pointer().get_value::<u128>())
+    }
+
+    /// $BB left to mint once `bb_supply` has been minted
+    fn budget_left(&self, bb_supply: u128) -> u128 {
+        MAX_SUPPLY.saturating_sub(bb_supply)
+    }
+
+    /// Amount a claim of `amount` can fill with `remaining_budget` left, failing
+    /// once the budget is exhausted
+    fn claim_target(&self, amount: u128, remaining_budget: u128) -> Result<u128> {
+        if remaining_budget == 0 {
+            return Err(anyhow!("Max BB supply reached"));
+        }
+
+        Ok(amount.min(remaining_budget))
+    }
+
+    pub fn get_remaining_budget(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.remaining_budget().to_le_bytes().to_vec();
+
         Ok(response)
     }
 
@@ -416,6 +1205,15 @@ impl BB {
 
         let beep_boop_tokens = self.retrieve_beep_boop_tokens_from_contract(beep_boop_amount)?;
 
+        for token in &beep_boop_tokens {
+            self.record_event(
+                EventKind::SwappedToNft,
+                &token.id,
+                &context.myself,
+                token.value * MAX_CLAIM_PER_NFT,
+            )?;
+        }
+
         contract_beep_boop_balance -= beep_boop_amount;
         self.contract_beep_boop_balance_pointer()
             .set_value(contract_beep_boop_balance);
@@ -440,6 +1238,9 @@ impl BB {
         let context = self.context()?;
         let mut response = CallResponse::default();
 
+        // inputs: opcode, then eligibility proofs
+        let proofs = EligibilityProof::parse_all(context.inputs.get(1..).unwrap_or_default())?;
+
         if context.incoming_alkanes.0.is_empty() {
             return Err(anyhow!("No BEEP BOOP tokens provided for swap"));
         }
@@ -450,7 +1251,7 @@ impl BB {
 
         // Separate original BEEP BOOP tokens from non-original tokens
         for alkane in &context.incoming_alkanes.0 {
-            if self.is_original(&alkane.id)? {
+            if self.is_original(&alkane.id, &proofs)? {
                 total_incoming_beep_boop += alkane.value;
                 has_original_token = true;
             } else {
@@ -463,7 +1264,7 @@ impl BB {
         }
 
         for alkane in &context.incoming_alkanes.0 {
-            if self.is_original(&alkane.id)? {
+            if self.is_original(&alkane.id, &proofs)? {
                 let total_staked_blocks = self.get_total_staked_blocks_from_contract(&alkane.id)?;
                 if total_staked_blocks < MIN_STAKED_BLOCKS {
                     return Err(anyhow!(
@@ -479,10 +1280,22 @@ impl BB {
 
         let bb_amount = total_incoming_beep_boop * MAX_CLAIM_PER_NFT;
 
+        // Check the supply before touching any pool or balance
+        if bb_amount > self.remaining_budget() {
+            return Err(anyhow!("Would exceed max BB supply"));
+        }
+
         for alkane in &context.incoming_alkanes.0 {
-            if self.is_original(&alkane.id)? {
+            if self.is_original(&alkane.id, &proofs)? {
                 // Store in used pool since these tokens were previously retrieved from the contract
                 self.store_beep_boop_token_in_used_pool(&alkane.id, alkane.value)?;
+
+                self.record_event(
+                    EventKind::SwappedToBB,
+                    &alkane.id,
+                    &context.myself,
+                    alkane.value * MAX_CLAIM_PER_NFT,
+                )?;
             }
         }
 
@@ -494,10 +1307,6 @@ impl BB {
             .set_value(contract_beep_boop_balance);
 
         let current_bb_supply = self.bb_supply_pointer().get_value::<u128>();
-        if current_bb_supply + bb_amount > MAX_SUPPLY {
-            return Err(anyhow!("Would exceed max BB supply"));
-        }
-
         self.bb_supply_pointer()
             .set_value(current_bb_supply + bb_amount);
 
@@ -529,6 +1338,9 @@ impl BB {
         let context = self.context()?;
         let response = CallResponse::default();
 
+        // inputs: opcode, then eligibility proofs
+        let proofs = EligibilityProof::parse_all(context.inputs.get(1..).unwrap_or_default())?;
+
         if context.incoming_alkanes.0.is_empty() {
             return Err(anyhow!("Must provide BEEP BOOP tokens to deposit"));
         }
@@ -536,7 +1348,7 @@ impl BB {
         let mut total_beep_boop_deposited = 0u128;
 
         for alkane in &context.incoming_alkanes.0 {
-            if !self.is_original(&alkane.id)? {
+            if !self.is_original(&alkane.id, &proofs)? {
                 return Err(anyhow!("Only original BEEP BOOP"));
             }
         }
@@ -544,6 +1356,13 @@ impl BB {
         for alkane in &context.incoming_alkanes.0 {
             self.store_beep_boop_token_in_contract(&alkane.id)?;
             total_beep_boop_deposited += alkane.value;
+
+            self.record_event(
+                EventKind::Deposited,
+                &alkane.id,
+                &context.caller,
+                alkane.value,
+            )?;
         }
 
         let mut contract_beep_boop_balance_pointer = self.contract_beep_boop_balance_pointer();
@@ -607,6 +1426,46 @@ impl BB {
         Ok(response)
     }
 
+    pub fn get_stored_beep_boop_alkane_id_binary(&self, index: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let key_bytes = index.to_le_bytes().to_vec();
+        let token_data = self
+            .contract_stored_beep_boop_tokens_pointer()
+            .select(&key_bytes)
+            .get();
+
+        if token_data.is_empty() {
+            return Err(anyhow!("No BEEP BOOP token stored at index {}", index));
+        }
+
+        let token_id = self.bytes_to_nft_id(&token_data)?;
+        response.data = self.alkane_id_to_bytes(&token_id);
+
+        Ok(response)
+    }
+
+    pub fn get_used_beep_boop_alkane_id_binary(&self, index: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let key_bytes = index.to_le_bytes().to_vec();
+        let token_data = self
+            .contract_used_beep_boop_tokens_pointer()
+            .select(&key_bytes)
+            .get();
+
+        if token_data.is_empty() {
+            return Err(anyhow!("No used BEEP BOOP token stored at index {}", index));
+        }
+
+        let token_id = self.bytes_to_nft_id(&token_data)?;
+        response.data = self.alkane_id_to_bytes(&token_id);
+
+        Ok(response)
+    }
+
     pub fn get_used_deposit_index(&self) -> Result<CallResponse> {
         let context = self.context()?;
         let mut response = CallResponse::forward(&context.incoming_alkanes);
@@ -627,25 +1486,207 @@ impl BB {
         Ok(response)
     }
 
+    pub fn get_events(&self, start: u128, limit: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = events::encode_events(&self.events_pointer(), start, limit);
+        Ok(response)
+    }
+
+    /// Append an event about an NFT to the event log
+    fn record_event(
+        &self,
+        kind: EventKind,
+        nft_id: &AlkaneId,
+        counterpart: &AlkaneId,
+        amount: u128,
+    ) -> Result<()> {
+        events::append_event(
+            &mut self.events_pointer(),
+            &Event {
+                kind,
+                height: u128::from(self.height()),
+                subject: *nft_id,
+                counterpart: *counterpart,
+                amount,
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /// Sum the rewards of an orbital over its stake sessions in every vault. The
+    /// open session only counts for LP tokens, an original NFT is not in a vault.
     fn calculate_total_rewards(&self, original_id: &AlkaneId, is_original: bool) -> Result<u128> {
+        let epochs = self.emission_epochs();
+
+        let total_rewards = self
+            .get_stake_contract_ids()
+            .iter()
+            .map(|stake_contract_id| {
+                self.calculate_vault_rewards(stake_contract_id, original_id, !is_original, &epochs)
+            })
+            .fold(0u128, u128::saturating_add);
+
+        Ok(total_rewards)
+    }
+
+    /// Rewards of an orbital in one vault, from its session history
+    ///
+    /// Closed sessions are scaled down to the vault's total staked blocks when
+    /// blocks were forfeited. Staked blocks not covered by any session, which
+    /// predate session tracking, pay the genesis rate.
+    fn calculate_vault_rewards(
+        &self,
+        stake_contract_id: &AlkaneId,
+        orbital_id: &AlkaneId,
+        include_open_session: bool,
+        epochs: &[(u128, u128)],
+    ) -> u128 {
         let total_staked_blocks = self
-            .get_total_staked_blocks_from_contract(original_id)
+            .get_total_staked_blocks_from_vault(stake_contract_id, orbital_id)
             .unwrap_or(0);
 
-        if is_original {
-            // Scale the rewards by adding 8 zeros (multiply by 10^8)
-            return Ok(total_staked_blocks.saturating_mul(REWARD_SCALE));
+        let sessions = match self.get_stake_sessions_from_vault(stake_contract_id, orbital_id) {
+            Ok(sessions) => sessions,
+            // Vaults without session history pay the genesis rate on every block
+            Err(_) => {
+                let current_staking_blocks = if include_open_session {
+                    self.get_current_staking_period(stake_contract_id, orbital_id)
+                        .unwrap_or(0)
+                } else {
+                    0
+                };
+                return total_staked_blocks
+                    .saturating_add(current_staking_blocks)
+                    .saturating_mul(REWARD_SCALE);
+            }
+        };
+
+        let open_until = if include_open_session {
+            Some(u128::from(self.height()))
+        } else {
+            None
+        };
+
+        self.session_rewards(epochs, &sessions, total_staked_blocks, open_until)
+    }
+
+    /// Rewards of a session history: closed sessions are priced by the epochs
+    /// they overlap and scaled to `total_staked_blocks`, the open session is
+    /// priced up to `open_until` and skipped when it is None
+    fn session_rewards(
+        &self,
+        epochs: &[(u128, u128)],
+        sessions: &[(u128, u128)],
+        total_staked_blocks: u128,
+        open_until: Option<u128>,
+    ) -> u128 {
+        let mut closed_blocks = 0u128;
+        let mut closed_rewards = 0u128;
+        let mut open_rewards = 0u128;
+
+        for &(start_height, end_height) in sessions {
+            if end_height == 0 {
+                if let Some(current_height) = open_until {
+                    open_rewards = open_rewards.saturating_add(self.emission_between(
+                        epochs,
+                        start_height,
+                        current_height,
+                    ));
+                }
+                continue;
+            }
+
+            closed_blocks = closed_blocks.saturating_add(end_height.saturating_sub(start_height));
+            closed_rewards = closed_rewards.saturating_add(self.emission_between(
+                epochs,
+                start_height,
+                end_height,
+            ));
         }
 
-        let current_staking_blocks = self.get_current_staking_period(original_id).unwrap_or(0);
-        let total_rewards = total_staked_blocks + current_staking_blocks;
+        if closed_blocks > total_staked_blocks {
+            closed_rewards = match closed_rewards.checked_mul(total_staked_blocks) {
+                Some(scaled) => scaled / closed_blocks,
+                None => (closed_rewards / closed_blocks).saturating_mul(total_staked_blocks),
+            };
+        } else {
+            let untracked_blocks = total_staked_blocks - closed_blocks;
+            closed_rewards =
+                closed_rewards.saturating_add(untracked_blocks.saturating_mul(REWARD_SCALE));
+        }
 
-        // Scale the rewards by adding 8 zeros (multiply by 10^8)
-        Ok(total_rewards.saturating_mul(REWARD_SCALE))
+        closed_rewards.saturating_add(open_rewards)
     }
 
+    /// Get the (start height, end height) of every stake session of an orbital in
+    /// a vault, the end height is 0 for an open session
+    fn get_stake_sessions_from_vault(
+        &self,
+        stake_contract_id: &AlkaneId,
+        orbital_id: &AlkaneId,
+    ) -> Result<Vec<(u128, u128)>> {
+        let mut sessions = Vec::new();
+
+        loop {
+            let cellpack = Cellpack {
+                target: *stake_contract_id,
+                inputs: vec![
+                    STAKE_GET_STAKE_SESSIONS,
+                    orbital_id.block,
+                    orbital_id.tx,
+                    sessions.len() as u128,
+                    STAKE_SESSIONS_PAGE_SIZE,
+                ],
+            };
+            let response =
+                self.staticcall(&cellpack, &AlkaneTransferParcel::default(), self.fuel())?;
+
+            if response.data.len() < 16 || (response.data.len() - 16) % STAKE_SESSION_SIZE != 0 {
+                return Err(anyhow!("Invalid stake sessions data from stake contract"));
+            }
+
+            let count = u128::from_le_bytes(response.data[0..16].try_into().unwrap());
+            let page = &response.data[16..];
+            if page.is_empty() {
+                break;
+            }
+
+            for session in page.chunks(STAKE_SESSION_SIZE) {
+                sessions.push((
+                    u128::from_le_bytes(session[0..16].try_into().unwrap()),
+                    u128::from_le_bytes(session[16..32].try_into().unwrap()),
+                ));
+            }
+
+            if sessions.len() as u128 >= count {
+                break;
+            }
+        }
+
+        Ok(sessions)
+    }
+
+    /// Sum the total staked blocks of an orbital across the registered stake contracts
     fn get_total_staked_blocks_from_contract(&self, alkane_id: &AlkaneId) -> Result<u128> {
-        let stake_contract_id = self.get_stake_contract_id();
+        let mut total_blocks = 0u128;
+        for stake_contract_id in self.get_stake_contract_ids() {
+            total_blocks = total_blocks.saturating_add(
+                self.get_total_staked_blocks_from_vault(&stake_contract_id, alkane_id)?,
+            );
+        }
+
+        Ok(total_blocks)
+    }
+
+    fn get_total_staked_blocks_from_vault(
+        &self,
+        stake_contract_id: &AlkaneId,
+        alkane_id: &AlkaneId,
+    ) -> Result<u128> {
+        let stake_contract_id = *stake_contract_id;
         let cellpack = Cellpack {
             target: stake_contract_id,
             inputs: vec![STAKE_GET_TOTAL_STAKED_BLOCKS, alkane_id.block, alkane_id.tx],
@@ -669,8 +1710,12 @@ impl BB {
         Ok(total_blocks)
     }
 
-    fn get_current_staking_period(&self, alkane_id: &AlkaneId) -> Result<u128> {
-        let staked_height = match self.try_get_staked_height(alkane_id) {
+    fn get_current_staking_period(
+        &self,
+        stake_contract_id: &AlkaneId,
+        alkane_id: &AlkaneId,
+    ) -> Result<u128> {
+        let staked_height = match self.try_get_staked_height(stake_contract_id, alkane_id) {
             Ok(height) => height,
             Err(_) => return Ok(0), // Not staked or failed to get height
         };
@@ -687,8 +1732,12 @@ impl BB {
         }
     }
 
-    fn try_get_staked_height(&self, alkane_id: &AlkaneId) -> Result<u128> {
-        let stake_contract_id = self.get_stake_contract_id();
+    fn try_get_staked_height(
+        &self,
+        stake_contract_id: &AlkaneId,
+        alkane_id: &AlkaneId,
+    ) -> Result<u128> {
+        let stake_contract_id = *stake_contract_id;
         let cellpack = Cellpack {
             target: stake_contract_id,
             inputs: vec![STAKE_GET_STAKED_HEIGHT, alkane_id.block, alkane_id.tx],
@@ -717,11 +1766,41 @@ impl BB {
         Ok(staked_height)
     }
 
+    /// Get the primary stake contract, the one set at initialize
     fn get_stake_contract_id(&self) -> AlkaneId {
-        AlkaneId {
-            block: BEEP_BOOP_BLOCK,
-            tx: BEEP_BOOP_STAKE_CONTRACT_TX,
+        self.get_stake_contract_ids()[0]
+    }
+
+    /// Get the registered stake contracts, the primary one first. Contracts
+    /// initialized before the id was stored fall back to the mainnet vault
+    fn get_stake_contract_ids(&self) -> Vec<AlkaneId> {
+        self.stake_contract_ids_or_default(self.stored_stake_contract_ids())
+    }
+
+    fn stake_contract_ids_or_default(&self, stake_contract_ids: Vec<AlkaneId>) -> Vec<AlkaneId> {
+        if stake_contract_ids.is_empty() {
+            return vec![AlkaneId {
+                block: BEEP_BOOP_BLOCK,
+                tx: BEEP_BOOP_STAKE_CONTRACT_TX,
+            }];
         }
+
+        stake_contract_ids
+    }
+
+    fn stored_stake_contract_ids(&self) -> Vec<AlkaneId> {
+        self.read_stake_contract_ids(&self.stake_contracts_pointer())
+    }
+
+    fn read_stake_contract_ids<P: KeyValuePointer>(&self, stake_contracts: &P) -> Vec<AlkaneId> {
+        let count = stake_contracts.get_value::<u128>();
+
+        (0..count)
+            .filter_map(|i| {
+                let id_bytes = stake_contracts.select(&i.to_le_bytes().to_vec()).get();
+                self.bytes_to_nft_id(&id_bytes).ok()
+            })
+            .collect()
     }
 
     pub fn alkane_id_to_bytes(&self, alkane_id: &AlkaneId) -> Vec<u8> {
@@ -731,43 +1810,27 @@ impl BB {
         bytes
     }
 
+    /// Find the orbital behind an LP token in whichever stake contract minted it
     fn get_original_nft_from_lp(&self, lp_id: &AlkaneId) -> Result<AlkaneId> {
-        let stake_contract_id = self.get_stake_contract_id();
-
-        let cellpack = Cellpack {
-            target: stake_contract_id,
-            inputs: vec![STAKE_GET_STAKED_BY_LP, lp_id.block, lp_id.tx],
-        };
-
-        let response = self
-            .staticcall(&cellpack, &AlkaneTransferParcel::default(), self.fuel())
-            .map_err(|_| {
-                anyhow!(
-                    "Failed to call stake contract for LP token {}",
-                    format!("{}:{}", lp_id.block, lp_id.tx)
-                )
-            })?;
-
-        let id_string = String::from_utf8(response.data)
-            .map_err(|_| anyhow!("Invalid UTF-8 response from stake contract"))?;
-
-        let parts: Vec<&str> = id_string.split(':').collect();
+        for stake_contract_id in self.get_stake_contract_ids() {
+            let cellpack = Cellpack {
+                target: stake_contract_id,
+                inputs: vec![STAKE_GET_STAKED_BY_LP_BINARY, lp_id.block, lp_id.tx],
+            };
 
-        if parts.len() != 2 {
-            return Err(anyhow!(
-                "Invalid LP token response format: expected 'block:tx', got '{}'",
-                id_string
-            ));
+            if let Ok(response) =
+                self.staticcall(&cellpack, &AlkaneTransferParcel::default(), self.fuel())
+            {
+                if let Ok(original_id) = self.bytes_to_nft_id(&response.data) {
+                    return Ok(original_id);
+                }
+            }
         }
 
-        let block = parts[0]
-            .parse::<u128>()
-            .map_err(|_| anyhow!("Invalid block number in response: '{}'", parts[0]))?;
-        let tx = parts[1]
-            .parse::<u128>()
-            .map_err(|_| anyhow!("Invalid tx number in response: '{}'", parts[1]))?;
-
-        Ok(AlkaneId { block, tx })
+        Err(anyhow!(
+            "Failed to call stake contract for LP token {}",
+            format!("{}:{}", lp_id.block, lp_id.tx)
+        ))
     }
 
     pub fn get_value_per_mint(&self) -> Result<CallResponse> {
@@ -890,12 +1953,94 @@ impl BB {
         Ok(tokens)
     }
 
+    /// Check whether any stake contract has already verified the orbital as eligible
     pub fn verify_id_collection(&self, orbital_id: &AlkaneId) -> bool {
-        orbital_id.block == BEEP_BOOP_BLOCK && BEEP_BOOP_IDS.contains(&orbital_id.tx)
+        self.get_stake_contract_ids()
+            .into_iter()
+            .any(|stake_contract_id| {
+                let cellpack = Cellpack {
+                    target: stake_contract_id,
+                    inputs: vec![
+                        STAKE_GET_ORBITAL_COLLECTION,
+                        orbital_id.block,
+                        orbital_id.tx,
+                    ],
+                };
+
+                match self.staticcall(&cellpack, &AlkaneTransferParcel::default(), self.fuel()) {
+                    Ok(response) => response.data.len() == 32,
+                    Err(_) => false, // Not verified yet
+                }
+            })
+    }
+
+    /// Check whether an id is an eligible original orbital, using the proofs sent
+    /// with the call for orbitals the stake contract has not verified yet
+    fn is_original(&self, id: &AlkaneId, proofs: &[EligibilityProof]) -> Result<bool> {
+        if self.verify_id_collection(id) {
+            return Ok(true);
+        }
+
+        let proof = match proofs.iter().find(|proof| proof.orbital_id == *id) {
+            Some(proof) => proof,
+            None => return Ok(false),
+        };
+
+        let (root, leaf_count) = self.get_eligible_root_from_contract(&proof.collection_id)?;
+        if !proof.verify(root, leaf_count) {
+            return Err(anyhow!(
+                "Invalid eligibility proof for {}:{}",
+                id.block,
+                id.tx
+            ));
+        }
+
+        Ok(true)
+    }
+
+    /// Get the eligible root of a collection from the first stake contract that has one
+    fn get_eligible_root_from_contract(
+        &self,
+        collection_id: &AlkaneId,
+    ) -> Result<([u8; 32], u128)> {
+        let stake_contract_ids = self.get_stake_contract_ids();
+        let (last, others) = stake_contract_ids.split_last().unwrap();
+        for stake_contract_id in others {
+            if let Ok(root) = self.get_eligible_root_from_vault(stake_contract_id, collection_id) {
+                return Ok(root);
+            }
+        }
+
+        self.get_eligible_root_from_vault(last, collection_id)
     }
 
-    fn is_original(&self, id: &AlkaneId) -> Result<bool> {
-        Ok(self.verify_id_collection(id))
+    fn get_eligible_root_from_vault(
+        &self,
+        stake_contract_id: &AlkaneId,
+        collection_id: &AlkaneId,
+    ) -> Result<([u8; 32], u128)> {
+        let cellpack = Cellpack {
+            target: *stake_contract_id,
+            inputs: vec![
+                STAKE_GET_ELIGIBLE_ROOT,
+                collection_id.block,
+                collection_id.tx,
+            ],
+        };
+
+        let response = self.staticcall(&cellpack, &AlkaneTransferParcel::default(), self.fuel())?;
+
+        if response.data.len() != 48 {
+            return Err(anyhow!(
+                "Invalid eligible root length from stake contract: expected 48 bytes, got {}",
+                response.data.len()
+            ));
+        }
+
+        let root: [u8; 32] = response.data[0..32].try_into().unwrap();
+        let leaf_count = u128::from_le_bytes(response.data[32..48].try_into().unwrap());
+
+        Ok((root, leaf_count))
     }
 
     fn bytes_to_nft_id(&self, bytes: &[u8]) -> Result<AlkaneId> {
@@ -920,6 +2065,49 @@ impl BB {
 
     // Storage pointers
 
+    /// Get the storage claims and vesting releases write to
+    fn claim_storage(&self) -> ClaimStorage<StoragePointer> {
+        ClaimStorage {
+            bb_supply: self.bb_supply_pointer(),
+            total_claimed: self.total_claimed_pointer(),
+            claimed_amounts: self.claimed_amounts_pointer(),
+            vesting_duration: self.vesting_duration_pointer(),
+            vesting_positions: self.vesting_positions_pointer(),
+            vesting_positions_by_nft: self.vesting_positions_by_nft_pointer(),
+            events: self.events_pointer(),
+        }
+    }
+
+    /// Storage pointer for the event log, the count lives on the base pointer
+    fn events_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/events")
+    }
+
+    /// Storage pointer for the emission epochs added after the genesis epoch
+    fn emission_epochs_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/emission-epochs")
+    }
+
+    /// Storage pointer for the registered stake contracts, the primary one first
+    fn stake_contracts_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/stake-contracts")
+    }
+
+    /// Storage pointer for the number of blocks claimed $BB vests over
+    fn vesting_duration_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/vesting-duration")
+    }
+
+    /// Storage pointer for vesting positions by index
+    fn vesting_positions_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/vesting-positions")
+    }
+
+    /// Storage pointer for the vesting position indices of each NFT
+    fn vesting_positions_by_nft_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/vesting-positions-by-nft")
+    }
+
     /// Storage pointer for claimed amounts by alkane ID
     fn claimed_amounts_pointer(&self) -> StoragePointer {
         StoragePointer::from_keyword("/claimed-amounts")
@@ -0,0 +1,6 @@
+mod test_claims;
+mod test_emission;
+mod test_ids;
+mod test_stake_contracts;
+mod test_supply;
+mod test_vesting;
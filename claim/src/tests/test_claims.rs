@@ -0,0 +1,182 @@
+use crate::claim_storage::ClaimStorage;
+use crate::*;
+use orbitals_common::events::EVENT_SIZE;
+
+const ORBITAL_A: AlkaneId = AlkaneId { block: 2, tx: 10 };
+const ORBITAL_B: AlkaneId = AlkaneId { block: 2, tx: 11 };
+const BB_ID: AlkaneId = AlkaneId { block: 2, tx: 900 };
+const HEIGHT: u128 = 900_000;
+
+fn word(data: &[u8], offset: usize) -> u128 {
+    u128::from_le_bytes(data[offset..offset + 16].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_claimable_is_earned_minus_claimed() {
+        let bb = BB::default();
+
+        assert_eq!(bb.claimable(1_000, 100), 900);
+        assert_eq!(bb.claimable(100, 1_000), 0);
+    }
+
+    #[test]
+    fn test_claimable_capped_per_nft() {
+        let bb = BB::default();
+
+        assert_eq!(bb.claimable(u128::MAX, 0), MAX_CLAIM_PER_NFT);
+        assert_eq!(bb.claimable(u128::MAX, MAX_CLAIM_PER_NFT - 5), 5);
+        assert_eq!(bb.claimable(u128::MAX, MAX_CLAIM_PER_NFT), 0);
+    }
+
+    #[test]
+    fn test_commit_records_claims_and_supply() {
+        let bb = BB::default();
+        let mut storage = ClaimStorage::in_memory("/commit");
+        storage.bb_supply.set_value(1_000u128);
+        storage.total_claimed.set_value(1_000u128);
+
+        let total = bb
+            .commit_claims(
+                &mut storage,
+                &[(ORBITAL_A, 100, 900), (ORBITAL_B, 0, 500)],
+                &BB_ID,
+                HEIGHT,
+            )
+            .unwrap();
+
+        assert_eq!(total, 1_400);
+        assert_eq!(storage.bb_supply.get_value::<u128>(), 2_400);
+        assert_eq!(storage.total_claimed.get_value::<u128>(), 2_400);
+        assert_eq!(
+            storage
+                .claimed_amounts
+                .select(&bb.alkane_id_to_bytes(&ORBITAL_A))
+                .get_value::<u128>(),
+            1_000
+        );
+        assert_eq!(
+            storage
+                .claimed_amounts
+                .select(&bb.alkane_id_to_bytes(&ORBITAL_B))
+                .get_value::<u128>(),
+            500
+        );
+
+        // one RewardsClaimed event per NFT, paid in $BB
+        let events = events::encode_events(&storage.events, 0, 10);
+        assert_eq!(word(&events, 0), 2);
+        let first = &events[16..16 + EVENT_SIZE];
+        assert_eq!(word(first, 16), EventKind::RewardsClaimed as u128);
+        assert_eq!(word(first, 32), HEIGHT);
+        assert_eq!(word(first, 64), ORBITAL_A.tx);
+        assert_eq!(word(first, 96), BB_ID.tx);
+        assert_eq!(word(first, 112), 900);
+    }
+
+    #[test]
+    fn test_pay_transfers_without_vesting() {
+        let bb = BB::default();
+        let mut storage = ClaimStorage::in_memory("/pay-transfer");
+        let mut response = CallResponse::default();
+
+        let transferred = bb
+            .pay_claims(
+                &mut storage,
+                &mut response,
+                &[(ORBITAL_A, 0, 900), (ORBITAL_B, 0, 500)],
+                1_400,
+                &BB_ID,
+                HEIGHT,
+            )
+            .unwrap();
+
+        assert_eq!(transferred, 1_400);
+        assert_eq!(response.alkanes.0.len(), 1);
+        assert_eq!(response.alkanes.0[0].id, BB_ID);
+        assert_eq!(response.alkanes.0[0].value, 1_400);
+        assert_eq!(storage.vesting_positions.get_value::<u128>(), 0);
+    }
+
+    #[test]
+    fn test_pay_vests_one_position_per_nft() {
+        let bb = BB::default();
+        let mut storage = ClaimStorage::in_memory("/pay-vest");
+        storage.vesting_duration.set_value(100u128);
+        let mut response = CallResponse::default();
+
+        let transferred = bb
+            .pay_claims(
+                &mut storage,
+                &mut response,
+                &[(ORBITAL_A, 0, 900), (ORBITAL_B, 0, 500)],
+                1_400,
+                &BB_ID,
+                HEIGHT,
+            )
+            .unwrap();
+
+        assert_eq!(transferred, 0);
+        assert!(response.alkanes.0.is_empty());
+        assert_eq!(storage.vesting_positions.get_value::<u128>(), 2);
+        assert_eq!(
+            bb.get_vesting_position_entry(&storage.vesting_positions, 0)
+                .unwrap(),
+            (ORBITAL_A, 900, HEIGHT, 100, 0)
+        );
+        assert_eq!(
+            bb.get_vesting_position_entry(&storage.vesting_positions, 1)
+                .unwrap(),
+            (ORBITAL_B, 500, HEIGHT, 100, 0)
+        );
+
+        let nft_positions = storage
+            .vesting_positions_by_nft
+            .select(&bb.alkane_id_to_bytes(&ORBITAL_B));
+        assert_eq!(nft_positions.get_value::<u128>(), 1);
+        assert_eq!(
+            nft_positions
+                .select(&0u128.to_le_bytes().to_vec())
+                .get_value::<u128>(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_breakdown_layout() {
+        let bb = BB::default();
+
+        let data =
+            bb.encode_claim_breakdown(1_400, 1_400, &[(ORBITAL_A, 100, 900), (ORBITAL_B, 0, 500)]);
+
+        assert_eq!(data.len(), 48 + 2 * 48);
+        assert_eq!(word(&data, 0), 1_400);
+        assert_eq!(word(&data, 16), 1_400);
+        assert_eq!(word(&data, 32), 0);
+        assert_eq!(&data[48..80], &bb.alkane_id_to_bytes(&ORBITAL_A)[..]);
+        assert_eq!(word(&data, 80), 900);
+        assert_eq!(&data[96..128], &bb.alkane_id_to_bytes(&ORBITAL_B)[..]);
+        assert_eq!(word(&data, 128), 500);
+    }
+
+    #[test]
+    fn test_vesting_breakdown_reports_nothing_transferred() {
+        let bb = BB::default();
+
+        let data = bb.encode_claim_breakdown(900, 0, &[(ORBITAL_A, 0, 900)]);
+
+        assert_eq!(word(&data, 0), 900);
+        assert_eq!(word(&data, 16), 0);
+        assert_eq!(word(&data, 32), 900);
+    }
+
+    #[test]
+    fn test_empty_breakdown_is_just_the_totals() {
+        let bb = BB::default();
+
+        assert_eq!(bb.encode_claim_breakdown(0, 0, &[]), vec![0u8; 48]);
+    }
+}
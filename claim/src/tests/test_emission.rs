@@ -0,0 +1,120 @@
+use crate::*;
+
+// genesis rate until block 1000, then half
+const HALVING: [(u128, u128); 2] = [(0, REWARD_SCALE), (1_000, REWARD_SCALE / 2)];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_epoch_must_follow_the_last() {
+        let bb = BB::default();
+
+        let err = bb.check_epoch_start(1_000, 1_000, 500).unwrap_err();
+        assert_eq!(err.to_string(), "Epoch must start after block 1000");
+        assert!(bb.check_epoch_start(1_001, 1_000, 500).is_ok());
+    }
+
+    #[test]
+    fn test_epoch_cant_start_in_the_past() {
+        let bb = BB::default();
+
+        let err = bb.check_epoch_start(900, 0, 901).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Epoch can't start before the current block 901"
+        );
+        assert!(bb.check_epoch_start(901, 0, 901).is_ok());
+    }
+
+    #[test]
+    fn test_emission_within_one_epoch() {
+        let bb = BB::default();
+
+        assert_eq!(bb.emission_between(&HALVING, 100, 200), 100 * REWARD_SCALE);
+        assert_eq!(
+            bb.emission_between(&HALVING, 1_000, 1_100),
+            100 * REWARD_SCALE / 2
+        );
+        assert_eq!(bb.emission_between(&HALVING, 200, 200), 0);
+    }
+
+    #[test]
+    fn test_emission_across_epoch_boundary() {
+        let bb = BB::default();
+
+        // 100 blocks at the genesis rate, 100 at half
+        assert_eq!(
+            bb.emission_between(&HALVING, 900, 1_100),
+            100 * REWARD_SCALE + 100 * REWARD_SCALE / 2
+        );
+    }
+
+    #[test]
+    fn test_closed_sessions_across_epochs() {
+        let bb = BB::default();
+        let sessions = [(900, 1_000), (1_000, 1_200)];
+
+        assert_eq!(
+            bb.session_rewards(&HALVING, &sessions, 300, None),
+            100 * REWARD_SCALE + 200 * REWARD_SCALE / 2
+        );
+    }
+
+    #[test]
+    fn test_open_session_priced_up_to_height() {
+        let bb = BB::default();
+        let sessions = [(100, 200), (900, 0)];
+
+        assert_eq!(
+            bb.session_rewards(&HALVING, &sessions, 100, Some(1_100)),
+            100 * REWARD_SCALE + 100 * REWARD_SCALE + 100 * REWARD_SCALE / 2
+        );
+        // an original NFT is not in a vault, its open session doesn't count
+        assert_eq!(
+            bb.session_rewards(&HALVING, &sessions, 100, None),
+            100 * REWARD_SCALE
+        );
+    }
+
+    #[test]
+    fn test_forfeited_blocks_scale_closed_sessions() {
+        let bb = BB::default();
+        // 400 closed blocks, a quarter of them forfeited
+        let sessions = [(800, 1_000), (1_000, 1_200)];
+        let full = 200 * REWARD_SCALE + 200 * REWARD_SCALE / 2;
+
+        assert_eq!(
+            bb.session_rewards(&HALVING, &sessions, 300, None),
+            full * 3 / 4
+        );
+        assert_eq!(bb.session_rewards(&HALVING, &sessions, 0, None), 0);
+    }
+
+    #[test]
+    fn test_forfeiture_leaves_open_session_whole() {
+        let bb = BB::default();
+        let sessions = [(800, 1_000), (1_100, 0)];
+
+        assert_eq!(
+            bb.session_rewards(&HALVING, &sessions, 100, Some(1_200)),
+            200 * REWARD_SCALE / 2 + 100 * REWARD_SCALE / 2
+        );
+    }
+
+    #[test]
+    fn test_untracked_blocks_pay_genesis_rate() {
+        let bb = BB::default();
+        let sessions = [(1_000, 1_100)];
+
+        assert_eq!(
+            bb.session_rewards(&HALVING, &sessions, 150, None),
+            100 * REWARD_SCALE / 2 + 50 * REWARD_SCALE
+        );
+        assert_eq!(
+            bb.session_rewards(&HALVING, &[], 150, None),
+            150 * REWARD_SCALE
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use crate::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alkane_id_bytes_round_trip() {
+        let bb = BB::default();
+
+        for id in [
+            AlkaneId { block: 0, tx: 0 },
+            AlkaneId {
+                block: 2,
+                tx: 57751,
+            },
+            AlkaneId {
+                block: u128::MAX,
+                tx: 1,
+            },
+        ] {
+            let bytes = bb.alkane_id_to_bytes(&id);
+            assert_eq!(bytes.len(), 32);
+            assert_eq!(bb.bytes_to_nft_id(&bytes).unwrap(), id);
+        }
+    }
+
+    #[test]
+    fn test_alkane_id_bytes_layout() {
+        let bb = BB::default();
+
+        let bytes = bb.alkane_id_to_bytes(&AlkaneId {
+            block: 2,
+            tx: 57751,
+        });
+
+        assert_eq!(&bytes[0..16], &2u128.to_le_bytes());
+        assert_eq!(&bytes[16..32], &57751u128.to_le_bytes());
+    }
+
+    #[test]
+    fn test_binary_id_of_wrong_length_is_rejected() {
+        let bb = BB::default();
+
+        // the stake contract used to answer with a "block:tx" string
+        assert!(bb.bytes_to_nft_id(b"2:57751").is_err());
+        assert!(bb.bytes_to_nft_id(&[0u8; 31]).is_err());
+        assert!(bb.bytes_to_nft_id(&[0u8; 33]).is_err());
+        assert!(bb.bytes_to_nft_id(&[]).is_err());
+    }
+}
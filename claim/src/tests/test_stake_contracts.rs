@@ -0,0 +1,106 @@
+use crate::*;
+use orbitals_common::memory_pointer::MemoryPointer;
+
+const MAINNET_VAULT: AlkaneId = AlkaneId {
+    block: 2,
+    tx: 57751,
+};
+const SECOND_VAULT: AlkaneId = AlkaneId {
+    block: 2,
+    tx: 90_000,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_initialize_takes_the_given_vault() {
+        let bb = BB::default();
+
+        assert_eq!(
+            bb.initial_stake_contract_id(2, 90_000).unwrap(),
+            SECOND_VAULT
+        );
+        assert_eq!(
+            bb.initial_stake_contract_id(0, 5).unwrap(),
+            AlkaneId { block: 0, tx: 5 }
+        );
+    }
+
+    #[test]
+    fn test_initialize_without_vault_rejected() {
+        let bb = BB::default();
+
+        let err = bb.initial_stake_contract_id(0, 0).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Initialize needs the stake contract id as block, tx"
+        );
+    }
+
+    #[test]
+    fn test_unregistered_falls_back_to_mainnet_vault() {
+        let bb = BB::default();
+        let stake_contracts = MemoryPointer::from_keyword("/stake-contracts-empty");
+
+        let stored = bb.read_stake_contract_ids(&stake_contracts);
+        assert!(stored.is_empty());
+        assert_eq!(
+            bb.stake_contract_ids_or_default(stored),
+            vec![MAINNET_VAULT]
+        );
+    }
+
+    #[test]
+    fn test_vaults_keep_registration_order() {
+        let bb = BB::default();
+        let mut stake_contracts = MemoryPointer::from_keyword("/stake-contracts-order");
+
+        bb.push_stake_contract(&mut stake_contracts, &SECOND_VAULT)
+            .unwrap();
+        bb.push_stake_contract(&mut stake_contracts, &MAINNET_VAULT)
+            .unwrap();
+
+        let stored = bb.read_stake_contract_ids(&stake_contracts);
+        assert_eq!(stored, vec![SECOND_VAULT, MAINNET_VAULT]);
+        // the first registered vault stays the primary one
+        assert_eq!(bb.stake_contract_ids_or_default(stored)[0], SECOND_VAULT);
+    }
+
+    #[test]
+    fn test_duplicate_vault_rejected() {
+        let bb = BB::default();
+        let mut stake_contracts = MemoryPointer::from_keyword("/stake-contracts-duplicate");
+
+        bb.push_stake_contract(&mut stake_contracts, &MAINNET_VAULT)
+            .unwrap();
+        let err = bb
+            .push_stake_contract(&mut stake_contracts, &MAINNET_VAULT)
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "Stake contract 2:57751 already registered");
+        assert_eq!(
+            bb.read_stake_contract_ids(&stake_contracts),
+            vec![MAINNET_VAULT]
+        );
+    }
+
+    #[test]
+    fn test_malformed_entries_are_skipped() {
+        let bb = BB::default();
+        let mut stake_contracts = MemoryPointer::from_keyword("/stake-contracts-malformed");
+
+        bb.push_stake_contract(&mut stake_contracts, &MAINNET_VAULT)
+            .unwrap();
+        stake_contracts
+            .select(&1u128.to_le_bytes().to_vec())
+            .set(Arc::new(b"2:90000".to_vec()));
+        stake_contracts.set_value(2u128);
+
+        assert_eq!(
+            bb.read_stake_contract_ids(&stake_contracts),
+            vec![MAINNET_VAULT]
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use crate::claim_storage::ClaimStorage;
+use crate::*;
+
+const ORBITAL_A: AlkaneId = AlkaneId { block: 2, tx: 10 };
+const ORBITAL_B: AlkaneId = AlkaneId { block: 2, tx: 11 };
+const BB_ID: AlkaneId = AlkaneId { block: 2, tx: 900 };
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remaining_budget() {
+        let bb = BB::default();
+
+        assert_eq!(bb.budget_left(0), MAX_SUPPLY);
+        assert_eq!(bb.budget_left(MAX_SUPPLY - 1), 1);
+        assert_eq!(bb.budget_left(MAX_SUPPLY), 0);
+        // a supply past the cap never wraps around
+        assert_eq!(bb.budget_left(MAX_SUPPLY + 1), 0);
+    }
+
+    #[test]
+    fn test_claim_within_budget_is_full() {
+        let bb = BB::default();
+
+        assert_eq!(bb.claim_target(500, 1_000).unwrap(), 500);
+        assert_eq!(bb.claim_target(1_000, 1_000).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn test_claim_past_budget_is_partially_filled() {
+        let bb = BB::default();
+
+        let target = bb.claim_target(1_500, 1_200).unwrap();
+        assert_eq!(target, 1_200);
+
+        // the second NFT is cut down to fill exactly the last of the supply
+        let mut storage = ClaimStorage::in_memory("/supply-partial");
+        storage.bb_supply.set_value(MAX_SUPPLY - 1_200);
+        let total = bb
+            .commit_claims(
+                &mut storage,
+                &[(ORBITAL_A, 0, 1_000), (ORBITAL_B, 0, 200)],
+                &BB_ID,
+                900_000,
+            )
+            .unwrap();
+
+        assert_eq!(total, target);
+        assert_eq!(storage.bb_supply.get_value::<u128>(), MAX_SUPPLY);
+        assert_eq!(bb.budget_left(storage.bb_supply.get_value::<u128>()), 0);
+    }
+
+    #[test]
+    fn test_claim_all_limited_to_budget() {
+        let bb = BB::default();
+
+        assert_eq!(bb.claim_target(u128::MAX, 300).unwrap(), 300);
+    }
+
+    #[test]
+    fn test_exhausted_budget_rejected() {
+        let bb = BB::default();
+
+        let err = bb.claim_target(1, 0).unwrap_err();
+        assert_eq!(err.to_string(), "Max BB supply reached");
+        let err = bb
+            .claim_target(u128::MAX, bb.budget_left(MAX_SUPPLY))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Max BB supply reached");
+    }
+}
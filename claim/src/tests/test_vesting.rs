@@ -0,0 +1,234 @@
+use crate::claim_storage::ClaimStorage;
+use crate::*;
+use orbitals_common::memory_pointer::MemoryPointer;
+
+const ORBITAL: AlkaneId = AlkaneId { block: 2, tx: 10 };
+const OTHER_ORBITAL: AlkaneId = AlkaneId { block: 2, tx: 11 };
+const LP: AlkaneId = AlkaneId { block: 2, tx: 500 };
+const BB_ID: AlkaneId = AlkaneId { block: 2, tx: 900 };
+
+const TOTAL: u128 = 1_000;
+const START: u128 = 900_000;
+const DURATION: u128 = 100;
+
+/// Storage under `prefix` holding one vesting position for ORBITAL
+fn vesting_storage(prefix: &str) -> ClaimStorage<MemoryPointer> {
+    let bb = BB::default();
+    let mut storage = ClaimStorage::in_memory(prefix);
+    bb.add_vesting_position(&mut storage, &ORBITAL, TOTAL, START, DURATION)
+        .unwrap();
+    storage
+}
+
+fn word(data: &[u8], index: usize) -> u128 {
+    u128::from_le_bytes(data[index * 16..(index + 1) * 16].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nothing_vested_at_start() {
+        let bb = BB::default();
+
+        assert_eq!(bb.vested_amount(TOTAL, START, DURATION, START), 0);
+        // a height before the start never underflows
+        assert_eq!(bb.vested_amount(TOTAL, START, DURATION, START - 1), 0);
+    }
+
+    #[test]
+    fn test_linear_unlock_midway() {
+        let bb = BB::default();
+
+        assert_eq!(bb.vested_amount(TOTAL, START, DURATION, START + 50), 500);
+        assert_eq!(bb.vested_amount(TOTAL, START, DURATION, START + 1), 10);
+        assert_eq!(bb.vested_amount(7, START, 3, START + 1), 2);
+    }
+
+    #[test]
+    fn test_fully_vested_at_end() {
+        let bb = BB::default();
+
+        assert_eq!(
+            bb.vested_amount(TOTAL, START, DURATION, START + DURATION),
+            TOTAL
+        );
+        assert_eq!(bb.vested_amount(TOTAL, START, DURATION, u128::MAX), TOTAL);
+    }
+
+    #[test]
+    fn test_zero_duration_vests_immediately() {
+        let bb = BB::default();
+
+        assert_eq!(bb.vested_amount(TOTAL, START, 0, START), TOTAL);
+        assert_eq!(
+            bb.releasable_amount(0, TOTAL, START, 0, 0, START).unwrap(),
+            TOTAL
+        );
+    }
+
+    #[test]
+    fn test_release_twice() {
+        let bb = BB::default();
+
+        let first = bb
+            .releasable_amount(3, TOTAL, START, DURATION, 0, START + 30)
+            .unwrap();
+        assert_eq!(first, 300);
+
+        // nothing more unlocks within the same block
+        let err = bb
+            .releasable_amount(3, TOTAL, START, DURATION, first, START + 30)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Nothing vested to release for position 3");
+
+        let second = bb
+            .releasable_amount(3, TOTAL, START, DURATION, first, START + 80)
+            .unwrap();
+        assert_eq!(second, 500);
+
+        let last = bb
+            .releasable_amount(3, TOTAL, START, DURATION, first + second, u128::MAX)
+            .unwrap();
+        assert_eq!(first + second + last, TOTAL);
+    }
+
+    #[test]
+    fn test_nothing_to_release_at_start() {
+        let bb = BB::default();
+
+        let err = bb
+            .releasable_amount(0, TOTAL, START, DURATION, 0, START)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Nothing vested to release for position 0");
+    }
+
+    #[test]
+    fn test_owner_nft_or_lp_authorized() {
+        let bb = BB::default();
+        let mut storage = vesting_storage("/vesting-authorized");
+
+        // the NFT itself
+        let released = bb
+            .release_position(&mut storage, 0, &[ORBITAL], &BB_ID, START + 30)
+            .unwrap();
+        assert_eq!(released, 300);
+
+        // an LP token, which held_nft_ids resolves to its original NFT
+        let released = bb
+            .release_position(
+                &mut storage,
+                0,
+                &[OTHER_ORBITAL, LP, ORBITAL],
+                &BB_ID,
+                START + 50,
+            )
+            .unwrap();
+        assert_eq!(released, 200);
+
+        assert_eq!(
+            bb.get_vesting_position_entry(&storage.vesting_positions, 0)
+                .unwrap(),
+            (ORBITAL, TOTAL, START, DURATION, 500)
+        );
+        assert_eq!(storage.events.get_value::<u128>(), 2);
+    }
+
+    #[test]
+    fn test_unauthorized_nft_or_lp_rejected() {
+        let bb = BB::default();
+        let mut storage = vesting_storage("/vesting-unauthorized");
+
+        let err = bb
+            .release_position(&mut storage, 0, &[OTHER_ORBITAL, LP], &BB_ID, START + 50)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Must send NFT 2:10 or one of its LP tokens to release position 0"
+        );
+        assert!(bb
+            .release_position(&mut storage, 0, &[], &BB_ID, START + 50)
+            .is_err());
+
+        // nothing was released or logged
+        assert_eq!(
+            bb.get_vesting_position_entry(&storage.vesting_positions, 0)
+                .unwrap(),
+            (ORBITAL, TOTAL, START, DURATION, 0)
+        );
+        assert_eq!(storage.events.get_value::<u128>(), 0);
+    }
+
+    #[test]
+    fn test_position_follows_the_nft() {
+        let bb = BB::default();
+        let mut storage = vesting_storage("/vesting-transferred");
+
+        // the claimant releases what vested while they held the NFT
+        let released = bb
+            .release_position(&mut storage, 0, &[ORBITAL], &BB_ID, START + 30)
+            .unwrap();
+        assert_eq!(released, 300);
+
+        // then sells it, or swaps it into the pool: they can't release any more
+        assert!(bb
+            .release_position(&mut storage, 0, &[OTHER_ORBITAL], &BB_ID, START + 80)
+            .is_err());
+
+        // the second holder, sending the LP, gets what vested since
+        let released = bb
+            .release_position(&mut storage, 0, &[LP, ORBITAL], &BB_ID, START + 80)
+            .unwrap();
+        assert_eq!(released, 500);
+
+        let err = bb
+            .release_position(&mut storage, 0, &[LP, ORBITAL], &BB_ID, START + 80)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Nothing vested to release for position 0");
+        assert_eq!(
+            bb.get_vesting_position_entry(&storage.vesting_positions, 0)
+                .unwrap(),
+            (ORBITAL, TOTAL, START, DURATION, 800)
+        );
+    }
+
+    #[test]
+    fn test_missing_position_rejected() {
+        let bb = BB::default();
+        let mut storage = vesting_storage("/vesting-missing");
+
+        let err = bb
+            .release_position(&mut storage, 1, &[ORBITAL], &BB_ID, START + 50)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "No vesting position at index 1");
+    }
+
+    #[test]
+    fn test_entry_layout() {
+        let bb = BB::default();
+
+        let data = bb.encode_vesting_entry(7, &(ORBITAL, TOTAL, START, DURATION, 100), START + 25);
+
+        assert_eq!(data.len(), VESTING_ENTRY_SIZE);
+        let words: Vec<u128> = (0..VESTING_ENTRY_SIZE / 16)
+            .map(|index| word(&data, index))
+            .collect();
+        assert_eq!(words, vec![7, 2, 10, TOTAL, START, DURATION, 100, 250, 750]);
+    }
+
+    #[test]
+    fn test_positions_page() {
+        let bb = BB::default();
+
+        assert_eq!(bb.vesting_page(0, 10, 3), (0, 3));
+        assert_eq!(bb.vesting_page(1, 1, 3), (1, 2));
+        assert_eq!(bb.vesting_page(5, 10, 3), (3, 3));
+        assert_eq!(bb.vesting_page(0, 0, 3), (0, 0));
+        assert_eq!(
+            bb.vesting_page(0, u128::MAX, 500),
+            (0, VESTING_POSITIONS_PAGE_SIZE)
+        );
+        assert_eq!(bb.vesting_page(u128::MAX, u128::MAX, 500), (500, 500));
+    }
+}
@@ -0,0 +1,261 @@
+use alkanes_support::id::AlkaneId;
+use anyhow::{anyhow, Result};
+use rs_merkle::{algorithms::Sha256, Hasher, MerkleProof};
+
+// orbital id + collection id + leaf index + proof length
+const PROOF_HEADER_WORDS: usize = 6;
+
+/// Merkle inclusion proof of an orbital in the eligible set of a collection
+#[derive(Debug)]
+pub struct EligibilityProof {
+    pub orbital_id: AlkaneId,
+    pub collection_id: AlkaneId,
+    pub leaf_index: u128,
+    pub hashes: Vec<[u8; 32]>,
+}
+
+impl EligibilityProof {
+    /// Parse the proofs appended to the call inputs
+    ///
+    /// Each proof is laid out as orbital block, orbital tx, collection block,
+    /// collection tx, leaf index, proof length, followed by one pair of words
+    /// per proof hash (first and last 16 bytes, little-endian)
+    pub fn parse_all(inputs: &[u128]) -> Result<Vec<EligibilityProof>> {
+        let mut proofs = Vec::new();
+        let mut rest = inputs;
+
+        while !rest.is_empty() {
+            if rest.len() < PROOF_HEADER_WORDS {
+                return Err(anyhow!("Truncated eligibility proof header"));
+            }
+
+            let proof_len = usize::try_from(rest[5])
+                .map_err(|_| anyhow!("Invalid eligibility proof length"))?;
+            let hash_words = proof_len
+                .checked_mul(2)
+                .filter(|words| *words <= rest.len() - PROOF_HEADER_WORDS)
+                .ok_or_else(|| anyhow!("Truncated eligibility proof hashes"))?;
+
+            let hashes = rest[PROOF_HEADER_WORDS..PROOF_HEADER_WORDS + hash_words]
+                .chunks(2)
+                .map(|words| words_to_hash(words[0], words[1]))
+                .collect();
+
+            proofs.push(EligibilityProof {
+                orbital_id: AlkaneId {
+                    block: rest[0],
+                    tx: rest[1],
+                },
+                collection_id: AlkaneId {
+                    block: rest[2],
+                    tx: rest[3],
+                },
+                leaf_index: rest[4],
+                hashes,
+            });
+
+            rest = &rest[PROOF_HEADER_WORDS + hash_words..];
+        }
+
+        Ok(proofs)
+    }
+
+    /// Check the proof against a collection root built over `leaf_count` orbitals
+    pub fn verify(&self, root: [u8; 32], leaf_count: u128) -> bool {
+        if self.leaf_index >= leaf_count {
+            return false;
+        }
+
+        let leaf_count = match usize::try_from(leaf_count) {
+            Ok(count) => count,
+            Err(_) => return false,
+        };
+
+        // leaf_index < leaf_count, so it fits in usize as well
+        MerkleProof::<Sha256>::new(self.hashes.clone()).verify(
+            root,
+            &[self.leaf_index as usize],
+            &[eligibility_leaf(&self.orbital_id)],
+            leaf_count,
+        )
+    }
+}
+
+/// Leaf hash of an orbital id
+pub fn eligibility_leaf(orbital_id: &AlkaneId) -> [u8; 32] {
+    let mut bytes = Vec::with_capacity(32);
+    bytes.extend_from_slice(&orbital_id.block.to_le_bytes());
+    bytes.extend_from_slice(&orbital_id.tx.to_le_bytes());
+    Sha256::hash(&bytes)
+}
+
+/// Rebuild a 32 byte hash from the two little-endian words it is sent as
+pub fn words_to_hash(first: u128, second: u128) -> [u8; 32] {
+    let mut hash = [0u8; 32];
+    hash[0..16].copy_from_slice(&first.to_le_bytes());
+    hash[16..32].copy_from_slice(&second.to_le_bytes());
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rs_merkle::MerkleTree;
+
+    fn orbital(tx: u128) -> AlkaneId {
+        AlkaneId { block: 2, tx }
+    }
+
+    fn eligible_tree(count: u128) -> MerkleTree<Sha256> {
+        let leaves: Vec<[u8; 32]> = (0..count)
+            .map(|tx| eligibility_leaf(&orbital(tx)))
+            .collect();
+        MerkleTree::<Sha256>::from_leaves(&leaves)
+    }
+
+    /// Encode a proof the way callers append it to the inputs
+    fn proof_words(
+        orbital_id: &AlkaneId,
+        leaf_index: usize,
+        tree: &MerkleTree<Sha256>,
+    ) -> Vec<u128> {
+        let hashes = tree.proof(&[leaf_index]).proof_hashes().to_vec();
+
+        let mut words = vec![
+            orbital_id.block,
+            orbital_id.tx,
+            2,
+            31064,
+            leaf_index as u128,
+            hashes.len() as u128,
+        ];
+        for hash in hashes {
+            words.push(u128::from_le_bytes(hash[0..16].try_into().unwrap()));
+            words.push(u128::from_le_bytes(hash[16..32].try_into().unwrap()));
+        }
+        words
+    }
+
+    #[test]
+    fn test_valid_proof_verifies() {
+        let tree = eligible_tree(5);
+        let root = tree.root().unwrap();
+
+        let proofs = EligibilityProof::parse_all(&proof_words(&orbital(3), 3, &tree)).unwrap();
+
+        assert_eq!(proofs.len(), 1);
+        assert_eq!(proofs[0].orbital_id, orbital(3));
+        assert_eq!(
+            proofs[0].collection_id,
+            AlkaneId {
+                block: 2,
+                tx: 31064
+            }
+        );
+        assert!(proofs[0].verify(root, 5));
+    }
+
+    #[test]
+    fn test_every_leaf_of_an_odd_tree_verifies() {
+        let tree = eligible_tree(7);
+        let root = tree.root().unwrap();
+
+        for index in 0..7 {
+            let words = proof_words(&orbital(index as u128), index, &tree);
+            let proofs = EligibilityProof::parse_all(&words).unwrap();
+            assert!(proofs[0].verify(root, 7), "leaf {} should verify", index);
+        }
+    }
+
+    #[test]
+    fn test_several_proofs_parse_in_order() {
+        let tree = eligible_tree(5);
+        let root = tree.root().unwrap();
+
+        let mut words = proof_words(&orbital(1), 1, &tree);
+        words.extend(proof_words(&orbital(4), 4, &tree));
+        let proofs = EligibilityProof::parse_all(&words).unwrap();
+
+        assert_eq!(proofs.len(), 2);
+        assert_eq!(proofs[0].orbital_id, orbital(1));
+        assert_eq!(proofs[1].orbital_id, orbital(4));
+        assert!(proofs.iter().all(|proof| proof.verify(root, 5)));
+    }
+
+    #[test]
+    fn test_wrong_leaf_is_rejected() {
+        let tree = eligible_tree(5);
+        let root = tree.root().unwrap();
+
+        // a proof for leaf 3 presented for an orbital that is not in the set
+        let proofs = EligibilityProof::parse_all(&proof_words(&orbital(9), 3, &tree)).unwrap();
+        assert!(!proofs[0].verify(root, 5));
+
+        // a real member presented at another member's position
+        let proofs = EligibilityProof::parse_all(&proof_words(&orbital(2), 3, &tree)).unwrap();
+        assert!(!proofs[0].verify(root, 5));
+    }
+
+    #[test]
+    fn test_leaf_index_out_of_range_is_rejected() {
+        let tree = eligible_tree(5);
+        let root = tree.root().unwrap();
+
+        let mut words = proof_words(&orbital(3), 3, &tree);
+        words[4] = 5;
+        let proofs = EligibilityProof::parse_all(&words).unwrap();
+
+        assert!(!proofs[0].verify(root, 5));
+    }
+
+    #[test]
+    fn test_proof_against_other_root_is_rejected() {
+        let other_root = eligible_tree(6).root().unwrap();
+
+        let proofs =
+            EligibilityProof::parse_all(&proof_words(&orbital(3), 3, &eligible_tree(5))).unwrap();
+
+        assert!(!proofs[0].verify(other_root, 6));
+    }
+
+    #[test]
+    fn test_no_proofs() {
+        assert!(EligibilityProof::parse_all(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_truncated_header_is_malformed() {
+        let err = EligibilityProof::parse_all(&[2, 3, 2, 31064, 3]).unwrap_err();
+
+        assert_eq!(err.to_string(), "Truncated eligibility proof header");
+    }
+
+    #[test]
+    fn test_missing_hash_words_are_malformed() {
+        let tree = eligible_tree(5);
+        let mut words = proof_words(&orbital(3), 3, &tree);
+        words.pop();
+
+        let err = EligibilityProof::parse_all(&words).unwrap_err();
+
+        assert_eq!(err.to_string(), "Truncated eligibility proof hashes");
+    }
+
+    #[test]
+    fn test_oversized_proof_length_is_malformed() {
+        let err = EligibilityProof::parse_all(&[2, 3, 2, 31064, 3, u128::MAX]).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid eligibility proof length");
+
+        let err = EligibilityProof::parse_all(&[2, 3, 2, 31064, 3, 1, 7]).unwrap_err();
+        assert_eq!(err.to_string(), "Truncated eligibility proof hashes");
+    }
+
+    #[test]
+    fn test_words_to_hash_round_trip() {
+        let hash = eligibility_leaf(&orbital(42));
+        let first = u128::from_le_bytes(hash[0..16].try_into().unwrap());
+        let second = u128::from_le_bytes(hash[16..32].try_into().unwrap());
+
+        assert_eq!(words_to_hash(first, second), hash);
+    }
+}
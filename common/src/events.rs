@@ -0,0 +1,174 @@
+use alkanes_support::id::AlkaneId;
+use anyhow::{anyhow, Result};
+use metashrew_support::index_pointer::KeyValuePointer;
+use std::sync::Arc;
+
+// sequence + kind + height + subject id + counterpart id + amount
+pub const EVENT_SIZE: usize = 128;
+// Max events returned by a single range query
+pub const MAX_EVENTS_PER_PAGE: u128 = 50;
+
+/// Event kinds, numbered the same way in the stake and claim contracts
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EventKind {
+    Staked = 1,
+    Unstaked = 2,
+    LpMinted = 3,
+    RewardsClaimed = 4,
+    SwappedToNft = 5,
+    SwappedToBB = 6,
+    Deposited = 7,
+    VestingReleased = 8,
+    UnstakeRequested = 9,
+    UnstakeCompleted = 10,
+}
+
+/// A record of the event log
+pub struct Event {
+    pub kind: EventKind,
+    pub height: u128,
+    /// The NFT the event is about
+    pub subject: AlkaneId,
+    /// The other id involved: LP token, reward token or depositor
+    pub counterpart: AlkaneId,
+    pub amount: u128,
+}
+
+impl Event {
+    /// Encode the event as sequence, kind, height, subject block, subject tx,
+    /// counterpart block, counterpart tx and amount, each a little-endian u128
+    pub fn encode(&self, sequence: u128) -> Vec<u8> {
+        let mut data = Vec::with_capacity(EVENT_SIZE);
+        data.extend_from_slice(&sequence.to_le_bytes());
+        data.extend_from_slice(&(self.kind as u128).to_le_bytes());
+        data.extend_from_slice(&self.height.to_le_bytes());
+        data.extend_from_slice(&self.subject.block.to_le_bytes());
+        data.extend_from_slice(&self.subject.tx.to_le_bytes());
+        data.extend_from_slice(&self.counterpart.block.to_le_bytes());
+        data.extend_from_slice(&self.counterpart.tx.to_le_bytes());
+        data.extend_from_slice(&self.amount.to_le_bytes());
+        data
+    }
+}
+
+/// Append an event to the log at `events` and return its sequence number, the
+/// count lives on the base pointer
+pub fn append_event<P: KeyValuePointer>(events: &mut P, event: &Event) -> Result<u128> {
+    let sequence = events.get_value::<u128>();
+
+    events
+        .select(&sequence.to_le_bytes().to_vec())
+        .set(Arc::new(event.encode(sequence)));
+
+    let new_count = sequence
+        .checked_add(1)
+        .ok_or_else(|| anyhow!("Event log overflow"))?;
+    events.set_value(new_count);
+
+    Ok(sequence)
+}
+
+/// Encode the event count followed by up to `limit` events starting at sequence `start`
+pub fn encode_events<P: KeyValuePointer>(events: &P, start: u128, limit: u128) -> Vec<u8> {
+    let count = events.get_value::<u128>();
+    let end = start
+        .saturating_add(limit.min(MAX_EVENTS_PER_PAGE))
+        .min(count);
+
+    let mut data = count.to_le_bytes().to_vec();
+    for sequence in start..end {
+        data.extend_from_slice(&events.select(&sequence.to_le_bytes().to_vec()).get());
+    }
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory_pointer::MemoryPointer;
+
+    fn event(amount: u128) -> Event {
+        Event {
+            kind: EventKind::Staked,
+            height: 900_000,
+            subject: AlkaneId { block: 2, tx: 42 },
+            counterpart: AlkaneId {
+                block: 2,
+                tx: 1_000,
+            },
+            amount,
+        }
+    }
+
+    fn word(data: &[u8], index: usize) -> u128 {
+        u128::from_le_bytes(data[index * 16..(index + 1) * 16].try_into().unwrap())
+    }
+
+    #[test]
+    fn test_encode_layout() {
+        let data = event(144).encode(7);
+
+        assert_eq!(data.len(), EVENT_SIZE);
+        let words: Vec<u128> = (0..8).map(|index| word(&data, index)).collect();
+        assert_eq!(words, vec![7, 1, 900_000, 2, 42, 2, 1_000, 144]);
+    }
+
+    #[test]
+    fn test_kinds_keep_their_numbers() {
+        let kinds = [
+            EventKind::Staked,
+            EventKind::Unstaked,
+            EventKind::LpMinted,
+            EventKind::RewardsClaimed,
+            EventKind::SwappedToNft,
+            EventKind::SwappedToBB,
+            EventKind::Deposited,
+            EventKind::VestingReleased,
+            EventKind::UnstakeRequested,
+            EventKind::UnstakeCompleted,
+        ];
+
+        for (index, kind) in kinds.iter().enumerate() {
+            assert_eq!(*kind as u128, index as u128 + 1);
+        }
+    }
+
+    #[test]
+    fn test_append_assigns_sequences() {
+        let mut events = MemoryPointer::from_keyword("/events-append");
+
+        assert_eq!(append_event(&mut events, &event(1)).unwrap(), 0);
+        assert_eq!(append_event(&mut events, &event(2)).unwrap(), 1);
+        assert_eq!(events.get_value::<u128>(), 2);
+    }
+
+    #[test]
+    fn test_encode_events_pages() {
+        let mut events = MemoryPointer::from_keyword("/events-page");
+        for amount in 0..3 {
+            append_event(&mut events, &event(amount)).unwrap();
+        }
+
+        let data = encode_events(&events, 1, 10);
+        assert_eq!(data.len(), 16 + 2 * EVENT_SIZE);
+        assert_eq!(word(&data, 0), 3);
+        assert_eq!(
+            data[16..],
+            [event(1).encode(1), event(2).encode(2)].concat()[..]
+        );
+
+        assert_eq!(encode_events(&events, 5, 10), 3u128.to_le_bytes().to_vec());
+        assert_eq!(encode_events(&events, 0, 0).len(), 16);
+    }
+
+    #[test]
+    fn test_encode_events_caps_the_page() {
+        let mut events = MemoryPointer::from_keyword("/events-cap");
+        for amount in 0..MAX_EVENTS_PER_PAGE + 5 {
+            append_event(&mut events, &event(amount)).unwrap();
+        }
+
+        let data = encode_events(&events, 0, u128::MAX);
+        assert_eq!(data.len(), 16 + MAX_EVENTS_PER_PAGE as usize * EVENT_SIZE);
+    }
+}
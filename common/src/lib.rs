@@ -0,0 +1,6 @@
+//! Code shared by the stake and claim contracts
+
+pub mod eligibility;
+pub mod events;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod memory_pointer;
@@ -0,0 +1,41 @@
+use metashrew_support::index_pointer::KeyValuePointer;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+thread_local! {
+    static MEMORY_STORE: RefCell<HashMap<Vec<u8>, Arc<Vec<u8>>>> = RefCell::new(HashMap::new());
+}
+
+/// Thread-local stand-in for StoragePointer so storage code runs natively in tests
+///
+/// Every test thread gets its own store, so tests only need distinct keywords
+/// when they share a thread.
+#[derive(Clone, Debug)]
+pub struct MemoryPointer(Vec<u8>);
+
+impl KeyValuePointer for MemoryPointer {
+    fn wrap(word: &Vec<u8>) -> Self {
+        MemoryPointer(word.clone())
+    }
+
+    fn unwrap(&self) -> Arc<Vec<u8>> {
+        Arc::new(self.0.clone())
+    }
+
+    fn set(&mut self, value: Arc<Vec<u8>>) {
+        MEMORY_STORE.with(|store| store.borrow_mut().insert(self.0.clone(), value));
+    }
+
+    fn get(&self) -> Arc<Vec<u8>> {
+        MEMORY_STORE.with(|store| {
+            store
+                .borrow()
+                .get(&self.0)
+                .cloned()
+                .unwrap_or_else(|| Arc::new(Vec::new()))
+        })
+    }
+
+    fn inherits(&mut self, _from: &Self) {}
+}
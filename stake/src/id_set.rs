@@ -0,0 +1,85 @@
+use anyhow::{anyhow, Result};
+use metashrew_support::index_pointer::KeyValuePointer;
+use std::sync::Arc;
+
+/// Indexed set of 32-byte alkane ids with O(1) insert and swap-remove.
+///
+/// `entries` holds the length and the id at each index, `positions` holds the
+/// index of each id plus one so that 0 means "not in the set".
+pub struct IdSet<P: KeyValuePointer> {
+    entries: P,
+    positions: P,
+}
+
+impl<P: KeyValuePointer> IdSet<P> {
+    pub fn new(entries: P, positions: P) -> Self {
+        Self { entries, positions }
+    }
+
+    pub fn len(&self) -> u128 {
+        self.entries.get_value::<u128>()
+    }
+
+    /// Get the id at an index, empty when the index is out of range
+    pub fn get(&self, index: u128) -> Arc<Vec<u8>> {
+        self.entries.select(&index.to_le_bytes().to_vec()).get()
+    }
+
+    /// Append an id, returning false and leaving the set unchanged if it is
+    /// already there
+    pub fn insert(&mut self, id: &[u8]) -> Result<bool> {
+        let mut position_pointer = self.positions.select(&id.to_vec());
+        if position_pointer.get_value::<u128>() != 0 {
+            return Ok(false);
+        }
+
+        let length = self.len();
+        let new_length = length
+            .checked_add(1)
+            .ok_or_else(|| anyhow!("Id set overflow"))?;
+
+        self.entries
+            .select(&length.to_le_bytes().to_vec())
+            .set(Arc::new(id.to_vec()));
+        position_pointer.set_value(new_length);
+        self.entries.set_value(new_length);
+
+        Ok(true)
+    }
+
+    /// Remove an id by moving the last entry into its slot, returning whether
+    /// it was in the set
+    pub fn remove(&mut self, id: &[u8]) -> Result<bool> {
+        let mut position_pointer = self.positions.select(&id.to_vec());
+        let position = position_pointer.get_value::<u128>();
+
+        if position == 0 {
+            return Ok(false);
+        }
+
+        let index = position - 1;
+        let last_index = self
+            .len()
+            .checked_sub(1)
+            .ok_or_else(|| anyhow!("Id set position out of range"))?;
+
+        if index != last_index {
+            let last_entry = self.get(last_index);
+
+            self.entries
+                .select(&index.to_le_bytes().to_vec())
+                .set(last_entry.clone());
+            self.positions
+                .select(&last_entry.to_vec())
+                .set_value(position);
+        }
+
+        self.entries
+            .select(&last_index.to_le_bytes().to_vec())
+            .set(Arc::new(Vec::new()));
+        position_pointer.set_value(0u128);
+        self.entries.set_value(last_index);
+
+        Ok(true)
+    }
+}
@@ -1,6 +1,3 @@
-use image::{self, imageops, DynamicImage, ImageFormat, RgbaImage};
-use std::io::Cursor;
-
 use alkanes_runtime::{
     declare_alkane, message::MessageDispatch, runtime::AlkaneResponder, storage::StoragePointer,
     token::Token,
@@ -12,25 +9,71 @@ use metashrew_support::compat::to_arraybuffer_layout;
 use metashrew_support::index_pointer::KeyValuePointer;
 
 use anyhow::{anyhow, Result};
+use orbitals_common::eligibility::{self, EligibilityProof};
+use orbitals_common::events::{self, Event, EventKind};
+use rs_merkle::{algorithms::Sha256, Hasher};
+use serde_json::{json, Map, Value};
+use std::ops::Range;
 use std::sync::Arc;
 
+mod id_set;
+mod orbital_records;
 mod orbitals_ids;
-use orbitals_ids::BEEP_BOOP_IDS;
+mod render;
+#[cfg(test)]
+mod tests;
+use id_set::IdSet;
+use orbital_records::{OrbitalRecords, POSITION_SIZE};
+use orbitals_ids::{BEEP_BOOP_IDS_COUNT, BEEP_BOOP_IDS_ROOT};
+use render::RenderVariant;
 
 const ORBITAL_TEMPLATE_ID: u128 = 16802;
 const MAX_MINTS: u128 = 10000;
 const BEEP_BOOP_BLOCK: u128 = 2;
 const BEEP_BOOP_COLLECTION_ID: u128 = 31064;
+const BEEP_BOOP_COLLECTION: AlkaneId = AlkaneId {
+    block: BEEP_BOOP_BLOCK,
+    tx: BEEP_BOOP_COLLECTION_ID,
+};
 const CONTRACT_NAME: &str = "Beep Boop Intergalactic";
 const CONTRACT_SYMBOL: &str = "🖨️";
+// Max entries returned by a single paged query
+const MAX_PAGE_SIZE: u128 = 50;
+// start height + end height + LP id
+const STAKE_SESSION_SIZE: usize = 64;
+// Trait weights are in basis points, 10_000 is a 1x multiplier
+const TRAIT_WEIGHT_BASE: u128 = 10_000;
+// Early-unstake penalties are in basis points of the session's blocks
+const PENALTY_BASE: u128 = 10_000;
+// Claim contract opcode GetPosition reads pending rewards from
+const CLAIM_GET_AVAILABLE_TO_CLAIM: u128 = 302;
 const OVERLAY_BLUE_BYTES: &[u8] = include_bytes!("../assets/Blue.png");
 const OVERLAY_GLITCH_BYTES: &[u8] = include_bytes!("../assets/Glitch.png");
 const OVERLAY_GREEN_BYTES: &[u8] = include_bytes!("../assets/Green.png");
 const OVERLAY_PINK_BYTES: &[u8] = include_bytes!("../assets/Pink.png");
 
+/// Overlay tiers unlocked by accrued staked blocks (about 144 blocks a day):
+/// threshold, name and overlay image
+const OVERLAY_TIERS: [(u128, &str, &[u8]); 4] = [
+    (0, "Blue", OVERLAY_BLUE_BYTES),
+    (4_320, "Green", OVERLAY_GREEN_BYTES),    // ~30 days
+    (12_960, "Pink", OVERLAY_PINK_BYTES),     // ~90 days
+    (52_560, "Glitch", OVERLAY_GLITCH_BYTES), // ~1 year
+];
+
 #[derive(Default)]
 pub struct Staking(());
 
+/// Vault calls gated by the stake and unstake pause switches
+#[derive(Clone, Copy)]
+enum VaultAction {
+    Stake,
+    Unstake,
+    RequestUnstake,
+    CompleteUnstake,
+    CancelUnstake,
+}
+
 /// Implementation of AlkaneResponder trait for the collection
 impl AlkaneResponder for Staking {}
 
@@ -38,7 +81,7 @@ impl AlkaneResponder for Staking {}
 /// These messages define the available operations that can be performed on the contract
 #[derive(MessageDispatch)]
 enum StakingMessage {
-    /// Initialize the contract and perform premine
+    /// Initialize the contract and mint the admin token
     #[opcode(0)]
     Initialize,
 
@@ -66,14 +109,38 @@ enum StakingMessage {
     #[returns(u128)]
     GetOrbitalMinted,
 
-    /// Stake an orbital
+    /// Stake orbitals
+    ///
+    /// Orbitals not verified before need an eligibility proof in the inputs
     #[opcode(500)]
     Stake,
 
-    /// Unstake an orbital
+    /// Unstake orbitals by returning their LP tokens
+    ///
+    /// Orbitals unstaked before their lock ends forfeit part of the session's blocks
     #[opcode(501)]
     Unstake,
 
+    /// Stake orbitals locked for at least `lock_blocks` (or the global lock if longer)
+    ///
+    /// Eligibility proofs follow `lock_blocks` in the inputs
+    #[opcode(502)]
+    StakeLocked { lock_blocks: u128 },
+
+    /// Start unbonding orbitals: accrual stops now and the LP tokens sent are returned
+    #[opcode(503)]
+    RequestUnstake,
+
+    /// Return unbonded orbitals in exchange for their LP tokens, once the
+    /// unbonding period has passed since the request
+    #[opcode(504)]
+    CompleteUnstake,
+
+    /// Put unbonding orbitals back into staking under the lock they had before
+    /// the request, the LP tokens sent are returned
+    #[opcode(505)]
+    CancelUnstake,
+
     /// Check if an orbital is eligible to be staked
     #[opcode(506)]
     #[returns(u128)]
@@ -99,15 +166,273 @@ enum StakingMessage {
     #[returns(u128)]
     GetTotalStakedBlocks { block: u128, tx: u128 },
 
-    /// Get the total number of staked orbitals
+    /// Get the lifetime number of stakes, re-stakes included
     #[opcode(511)]
     #[returns(u128)]
     GetTotalStaked,
 
+    /// Get the lifetime number of unstakes
     #[opcode(512)]
     #[returns(u128)]
     GetTotalUnstaked,
 
+    /// Get a page of the stake session history of an orbital
+    ///
+    /// Returns the total session count (u128) followed by up to `limit` sessions
+    /// starting at `offset`, each encoded as start height (u128), end height
+    /// (u128, 0 while open) and LP id (block u128, tx u128), all little-endian
+    #[opcode(513)]
+    #[returns(Vec<u8>)]
+    GetStakeSessions {
+        block: u128,
+        tx: u128,
+        offset: u128,
+        limit: u128,
+    },
+
+    /// Get the number of orbitals currently staked in the vault
+    #[opcode(514)]
+    #[returns(u128)]
+    GetStakedOrbitalsCount,
+
+    /// Get a page of the currently staked orbitals
+    ///
+    /// Returns up to `limit` entries starting at `offset`, each encoded as orbital
+    /// id (block u128, tx u128), LP id (block u128, tx u128) and staked height
+    /// (u128), all little-endian
+    #[opcode(515)]
+    #[returns(Vec<u8>)]
+    GetStakedOrbitals { offset: u128, limit: u128 },
+
+    /// Get the LP id bound to an orbital
+    ///
+    /// Returns the LP id (block u128, tx u128, zeroed if none was ever minted),
+    /// followed by an "ever minted" byte and a "currently staked" byte
+    #[opcode(516)]
+    #[returns(Vec<u8>)]
+    GetLpByOrbital { block: u128, tx: u128 },
+
+    /// Get the vault metrics in one call
+    ///
+    /// Returns five little-endian u128 values: currently staked orbitals, lifetime
+    /// stakes, lifetime unstakes, LP instances minted and total accrued staked blocks
+    #[opcode(517)]
+    #[returns(Vec<u8>)]
+    GetVaultStats,
+
+    /// Get the orbital ID staked by an lp id as 32 bytes (block u128, tx u128)
+    #[opcode(518)]
+    #[returns(Vec<u8>)]
+    GetStakedByLpBinary { block: u128, tx: u128 },
+
+    /// Get the collection identifier as 32 bytes (block u128, tx u128)
+    #[opcode(519)]
+    #[returns(Vec<u8>)]
+    GetCollectionIdentifierBinary,
+
+    /// Get the staking tier progress of an orbital or LP id; this is the
+    /// overlay drawn only while no rarity weights are set
+    ///
+    /// Returns three little-endian u128 values: current tier, accrued staked
+    /// blocks and the threshold of the next tier (0 at the top tier)
+    #[opcode(520)]
+    #[returns(Vec<u8>)]
+    GetOverlayTier { block: u128, tx: u128 },
+
+    /// Get the rewards of an orbital or LP id weighted by the traits of the orbital
+    ///
+    /// Returns two little-endian u128 values: weighted rewards and the trait
+    /// multiplier in basis points (10000 = 1x)
+    #[opcode(521)]
+    #[returns(Vec<u8>)]
+    GetWeightedRewards { block: u128, tx: u128 },
+
+    /// Get the staked blocks an orbital forfeited by unstaking early
+    #[opcode(522)]
+    #[returns(u128)]
+    GetForfeitedBlocks { block: u128, tx: u128 },
+
+    /// Get the height the current stake of an orbital or LP id unlocks at (0 if not locked)
+    #[opcode(523)]
+    #[returns(u128)]
+    GetStakeUnlockHeight { block: u128, tx: u128 },
+
+    /// Get the unbonding state of an orbital or LP id
+    ///
+    /// Returns two little-endian u128 values: request height and the height the
+    /// orbital can be withdrawn at (both 0 when not unbonding)
+    #[opcode(524)]
+    #[returns(Vec<u8>)]
+    GetUnbonding { block: u128, tx: u128 },
+
+    /// Get a range of the event log
+    ///
+    /// Returns the event count (u128) followed by up to `limit` events starting at
+    /// sequence `start`, 128 bytes each: sequence, kind, height, orbital id, LP id
+    /// and amount, all little-endian u128 values
+    #[opcode(525)]
+    #[returns(Vec<u8>)]
+    GetEvents { start: u128, limit: u128 },
+
+    /// Get the full position of an orbital or LP id in one call
+    ///
+    /// Returns 145 bytes: orbital id (block u128, tx u128), LP id (block u128,
+    /// tx u128, zeroed if none was ever minted), NFT index (u128), is staked
+    /// (u8), staked height (u128, 0 if not staked), total staked blocks (u128),
+    /// current accrual (u128, blocks of the open session) and pending rewards
+    /// (u128, the $BB the claim contract set with SetClaimContract would pay
+    /// now, 0 if none is set), all little-endian. An orbital that was never
+    /// staked reads as zeros
+    #[opcode(526)]
+    #[returns(Vec<u8>)]
+    GetPosition { block: u128, tx: u128 },
+
+    /// Pause staking, requires the admin token
+    #[opcode(600)]
+    PauseStake,
+
+    /// Resume staking, requires the admin token
+    #[opcode(601)]
+    UnpauseStake,
+
+    /// Pause unstaking, requires the admin token
+    #[opcode(602)]
+    PauseUnstake,
+
+    /// Resume unstaking, requires the admin token
+    #[opcode(603)]
+    UnpauseUnstake,
+
+    /// Get the pause status
+    ///
+    /// Returns two bytes: stake paused and unstake paused
+    #[opcode(604)]
+    #[returns(Vec<u8>)]
+    GetPauseStatus,
+
+    /// Register an eligible collection, requires the admin token
+    #[opcode(605)]
+    AddEligibleCollection { block: u128, tx: u128 },
+
+    /// Set the Merkle root of the eligible orbitals of a collection, requires
+    /// the admin token
+    #[opcode(606)]
+    SetEligibleRoot {
+        collection_block: u128,
+        collection_tx: u128,
+        root_first: u128,
+        root_second: u128,
+        leaf_count: u128,
+    },
+
+    /// Get the eligible collections as concatenated ids (block u128, tx u128)
+    #[opcode(607)]
+    #[returns(Vec<u8>)]
+    GetEligibleCollections,
+
+    /// Get the collection id (block u128, tx u128) a verified or previously
+    /// staked orbital belongs to
+    #[opcode(608)]
+    #[returns(Vec<u8>)]
+    GetOrbitalCollection { block: u128, tx: u128 },
+
+    /// Get the eligible Merkle root (32 bytes) and leaf count (u128) of a collection
+    #[opcode(609)]
+    #[returns(Vec<u8>)]
+    GetEligibleRoot { block: u128, tx: u128 },
+
+    /// Set the reward weight of a trait in basis points, requires the admin token.
+    /// 0 is a real weight that zeroes the rewards; ClearTraitWeight unsets it
+    ///
+    /// The trait follows `weight` as UTF-8 `trait_type`, a zero byte and `value`,
+    /// packed 16 bytes per little-endian word
+    #[opcode(610)]
+    SetTraitWeight { weight: u128 },
+
+    /// Get the reward weight of a trait in basis points (10000 when unset), the
+    /// trait packed as in SetTraitWeight
+    #[opcode(611)]
+    #[returns(u128)]
+    GetTraitWeight,
+
+    /// Set the rarity weights of the overlays, requires the admin token
+    ///
+    /// While any weight is set, each index gets a fixed overlay drawn from its
+    /// hash; all zero goes back to overlays unlocked by staking tiers
+    #[opcode(612)]
+    SetOverlayWeights {
+        blue: u128,
+        green: u128,
+        pink: u128,
+        glitch: u128,
+    },
+
+    /// Get the rarity weights of the overlays as four little-endian u128 values
+    /// (Blue, Green, Pink, Glitch)
+    #[opcode(613)]
+    #[returns(Vec<u8>)]
+    GetOverlayWeights,
+
+    /// Set the global minimum lock in blocks and the share of a session's
+    /// blocks forfeited by unstaking early, in basis points; requires the admin token
+    #[opcode(614)]
+    SetLockup {
+        lock_blocks: u128,
+        penalty_bps: u128,
+    },
+
+    /// Get the lockup parameters
+    ///
+    /// Returns three little-endian u128 values: global lock in blocks, penalty in
+    /// basis points and the staked blocks forfeited across the vault
+    #[opcode(615)]
+    #[returns(Vec<u8>)]
+    GetLockup,
+
+    /// Set the unbonding period in blocks, 0 disables unbonding and keeps the
+    /// instant Unstake; requires the admin token
+    #[opcode(616)]
+    SetUnbondingBlocks { blocks: u128 },
+
+    /// Get the unbonding period in blocks
+    #[opcode(617)]
+    #[returns(u128)]
+    GetUnbondingBlocks,
+
+    /// Add an orbital staked before the staked set existed to the set and the
+    /// vault counters; requires the admin token
+    ///
+    /// # Arguments
+    /// * `lp_block` - The block of the LP token bound to the orbital
+    /// * `lp_tx` - The tx of the LP token bound to the orbital
+    #[opcode(618)]
+    BackfillStakedOrbital { lp_block: u128, lp_tx: u128 },
+
+    /// Unset the reward weight of a trait so it counts as 1x again, requires the
+    /// admin token; the trait follows the opcode, packed as in SetTraitWeight
+    #[opcode(619)]
+    ClearTraitWeight,
+
+    /// Seed the eligibility registry with the Beep Boop collection and its
+    /// compiled-in root, as Initialize does; for vaults upgraded in place from
+    /// before the registry existed. Requires the admin token
+    #[opcode(620)]
+    SeedRegistry,
+
+    /// Set the claim contract GetPosition reads pending rewards from, requires
+    /// the admin token
+    ///
+    /// # Arguments
+    /// * `block` - The block of the claim contract
+    /// * `tx` - The tx of the claim contract
+    #[opcode(621)]
+    SetClaimContract { block: u128, tx: u128 },
+
+    /// Get the claim contract id (block u128, tx u128), zeroed when unset
+    #[opcode(622)]
+    #[returns(Vec<u8>)]
+    GetClaimContract,
+
     //
     /// Get the collection identifier
     #[opcode(998)]
@@ -129,6 +454,43 @@ enum StakingMessage {
     #[opcode(1002)]
     #[returns(String)]
     GetAttributes { index: u128 },
+
+    /// Get an SVG document layering the overlay on the base image, without decoding any pixels
+    ///
+    /// # Arguments
+    /// * `index` - The index of the orbital
+    #[opcode(1003)]
+    #[returns(String)]
+    GetDataSvg { index: u128 },
+
+    /// Get the content type of the SVG render
+    #[opcode(1004)]
+    #[returns(String)]
+    GetSvgContentType,
+
+    /// Render a variant of a specific orbital
+    ///
+    /// # Arguments
+    /// * `index` - The index of the orbital
+    /// * `variant` - 0 raw upstream image, 1 composite PNG, 2 composite thumbnail PNG, 3 composite SVG
+    /// * `edge` - Longest side of the thumbnail in pixels (16 to 512), ignored by other variants
+    #[opcode(1005)]
+    #[returns(Vec<u8>)]
+    GetRender {
+        index: u128,
+        variant: u128,
+        edge: u128,
+    },
+
+    /// Get the content type of a render variant, detected from the upstream
+    /// image for the raw variant
+    ///
+    /// # Arguments
+    /// * `index` - The index of the orbital
+    /// * `variant` - The variant selector, as passed to GetRender
+    #[opcode(1006)]
+    #[returns(String)]
+    GetRenderContentType { index: u128, variant: u128 },
 }
 
 /// Implementation of Token trait
@@ -151,6 +513,8 @@ impl Staking {
         let context = self.context()?;
         let mut response = CallResponse::forward(&context.incoming_alkanes);
 
+        self.seed_beep_boop_collection()?;
+
         response.alkanes.0.push(AlkaneTransfer {
             id: context.myself.clone(),
             value: 1u128,
@@ -159,326 +523,1739 @@ impl Staking {
         Ok(response)
     }
 
-    pub fn get_name(&self) -> Result<CallResponse> {
+    /// Pause staking (admin only)
+    pub fn pause_stake(&self) -> Result<CallResponse> {
+        self.set_pause_flag(self.stake_paused_pointer(), true)
+    }
+
+    /// Resume staking (admin only)
+    pub fn unpause_stake(&self) -> Result<CallResponse> {
+        self.set_pause_flag(self.stake_paused_pointer(), false)
+    }
+
+    /// Pause unstaking (admin only)
+    pub fn pause_unstake(&self) -> Result<CallResponse> {
+        self.set_pause_flag(self.unstake_paused_pointer(), true)
+    }
+
+    /// Resume unstaking (admin only)
+    pub fn unpause_unstake(&self) -> Result<CallResponse> {
+        self.set_pause_flag(self.unstake_paused_pointer(), false)
+    }
+
+    fn set_pause_flag(&self, mut pointer: StoragePointer, paused: bool) -> Result<CallResponse> {
+        self.only_admin()?;
+
         let context = self.context()?;
-        let mut response = CallResponse::forward(&context.incoming_alkanes);
+        let response = CallResponse::forward(&context.incoming_alkanes);
 
-        response.data = self.name().into_bytes();
+        pointer.set_value(paused as u128);
 
         Ok(response)
     }
 
-    pub fn get_symbol(&self) -> Result<CallResponse> {
+    pub fn get_pause_status(&self) -> Result<CallResponse> {
         let context = self.context()?;
         let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-        response.data = self.symbol().into_bytes();
+        response.data = vec![self.stake_paused() as u8, self.unstake_paused() as u8];
 
         Ok(response)
     }
 
-    /// Public stake function for orbitals
-    pub fn stake(&self) -> Result<CallResponse> {
+    /// Register a new eligible collection (admin only)
+    pub fn add_eligible_collection(&self, block: u128, tx: u128) -> Result<CallResponse> {
+        self.only_admin()?;
+
         let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
 
-        if context.incoming_alkanes.0.is_empty() {
-            return Err(anyhow!("Must send at least 1 orbital to stake"));
-        }
+        self.register_collection(&AlkaneId { block, tx })?;
 
-        for alkane in &context.incoming_alkanes.0 {
-            if !self.verify_id_collection(&alkane.id) {
-                return Err(anyhow!("Alkane ID not verified"));
-            }
-            let key = self.alkane_id_to_bytes(&alkane.id);
-            if self.stake_height_pointer().select(&key).get_value::<u128>() != 0 {
-                return Err(anyhow!(
-                    "Orbital {}:{} already staked",
-                    alkane.id.block,
-                    alkane.id.tx
-                ));
-            }
-        }
+        Ok(response)
+    }
 
-        let mut minted_lp_orbitals = Vec::new();
-        let mut newly_staked_count = 0u128;
+    /// Seed the registry of a vault upgraded in place (admin only)
+    pub fn seed_registry(&self) -> Result<CallResponse> {
+        self.only_admin()?;
 
-        for alkane in &context.incoming_alkanes.0 {
-            if alkane.value != 1 {
-                return Err(anyhow!("Alkane amount must be 1"));
-            }
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
 
-            // call GetNftIndex 999
-            let cellpack = Cellpack {
-                target: alkane.id,
-                inputs: vec![999],
-            };
+        self.seed_beep_boop_collection()?;
 
-            let call_response =
-                self.staticcall(&cellpack, &AlkaneTransferParcel::default(), self.fuel())?;
+        Ok(response)
+    }
 
-            let index = u128::from_le_bytes(call_response.data.try_into().unwrap());
+    pub fn set_claim_contract(&self, block: u128, tx: u128) -> Result<CallResponse> {
+        self.only_admin()?;
 
-            // Set stake block pointer for this alkane
-            self.stake_height_pointer()
-                .select(&self.alkane_id_to_bytes(&alkane.id))
-                .set_value(u128::from(self.height())); // this should be u128
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
 
-            let minted_lp_id = self
-                .instances_pointer()
-                .select(&index.to_le_bytes().to_vec())
-                .get();
+        self.claim_contract_pointer()
+            .set(Arc::new(self.alkane_id_to_bytes(&AlkaneId { block, tx })));
 
-            if minted_lp_id.len() == 0 {
-                let minted = self.create_mint_transfer(index)?;
-                minted_lp_orbitals.push(minted);
-                newly_staked_count += 1;
+        Ok(response)
+    }
 
-                let mut staked_pointer = self
-                    .staked_id_pointer()
-                    .select(&self.alkane_id_to_bytes(&minted.id));
+    pub fn get_claim_contract(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-                staked_pointer.set(Arc::new(self.alkane_id_to_bytes(&alkane.id)));
-            } else {
-                let minted_lp_id_bytes = minted_lp_id.as_ref();
+        let claim_contract = self.claim_contract_pointer().get();
+        response.data = if claim_contract.len() == 32 {
+            claim_contract.to_vec()
+        } else {
+            vec![0u8; 32]
+        };
 
-                let existing_alkane_id = AlkaneId {
-                    block: u128::from_le_bytes(minted_lp_id_bytes[0..16].try_into().unwrap()),
-                    tx: u128::from_le_bytes(minted_lp_id_bytes[16..32].try_into().unwrap()),
-                };
+        Ok(response)
+    }
 
-                let existing_alkane = AlkaneTransfer {
-                    id: existing_alkane_id,
-                    value: 1u128,
-                };
-                minted_lp_orbitals.push(existing_alkane.clone());
-                newly_staked_count += 1;
-            };
-        }
+    /// Register the Beep Boop collection with its compiled-in eligible root
+    fn seed_beep_boop_collection(&self) -> Result<()> {
+        self.register_collection(&BEEP_BOOP_COLLECTION)?;
+        self.set_collection_root(
+            &BEEP_BOOP_COLLECTION,
+            &BEEP_BOOP_IDS_ROOT,
+            BEEP_BOOP_IDS_COUNT,
+        );
 
-        // Increment total staked count only by newly staked orbitals
-        let mut total_staked_pointer = self.total_staked_pointer();
-        let current_total = total_staked_pointer.get_value::<u128>();
-        total_staked_pointer.set_value(current_total + newly_staked_count);
+        Ok(())
+    }
 
-        // clean incoming alkanes set as default
-        let mut response = CallResponse::default();
+    /// Set the Merkle root of the eligible orbitals of a collection (admin only)
+    ///
+    /// The root is sent as two little-endian words, first and last 16 bytes
+    pub fn set_eligible_root(
+        &self,
+        collection_block: u128,
+        collection_tx: u128,
+        root_first: u128,
+        root_second: u128,
+        leaf_count: u128,
+    ) -> Result<CallResponse> {
+        self.only_admin()?;
 
-        response.alkanes.0.extend(minted_lp_orbitals);
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        let collection_id = AlkaneId {
+            block: collection_block,
+            tx: collection_tx,
+        };
+        if self
+            .collection_registered_pointer()
+            .select(&self.alkane_id_to_bytes(&collection_id))
+            .get_value::<u128>()
+            == 0
+        {
+            return Err(anyhow!(
+                "Collection {}:{} is not registered",
+                collection_id.block,
+                collection_id.tx
+            ));
+        }
+
+        let root = eligibility::words_to_hash(root_first, root_second);
+        self.set_collection_root(&collection_id, &root, leaf_count);
 
         Ok(response)
     }
 
-    pub fn unstake(&self) -> Result<CallResponse> {
+    pub fn get_eligible_root(&self, block: u128, tx: u128) -> Result<CallResponse> {
         let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-        // Validate incoming alkanes
-        if context.incoming_alkanes.0.is_empty() {
-            return Err(anyhow!("Must send exactly 1 LP token to unstake"));
-        }
+        let root = self
+            .collection_root_pointer()
+            .select(&self.alkane_id_to_bytes(&AlkaneId { block, tx }))
+            .get();
 
-        if context.incoming_alkanes.0.len() != 1 {
-            return Err(anyhow!("Must send exactly 1 LP token to unstake"));
+        if root.len() != 48 {
+            return Err(anyhow!("Collection {}:{} has no eligible root", block, tx));
         }
 
-        if context.incoming_alkanes.0[0].value != 1 {
-            return Err(anyhow!("LP token amount must be 1"));
-        }
+        response.data = root.to_vec();
+        Ok(response)
+    }
 
-        let first_incoming_alkane_id = context.incoming_alkanes.0[0].id;
-        let lp_alkane_id_key = self.alkane_id_to_bytes(&first_incoming_alkane_id);
-        let staked_alkane_id = self.get_staked_orbital_id_by_lp_id(&lp_alkane_id_key)?;
+    /// Set the reward weight of a trait (admin only)
+    pub fn set_trait_weight(&self, weight: u128) -> Result<CallResponse> {
+        self.only_admin()?;
 
-        let staked_at_block = self
-            .stake_height_pointer()
-            .select(&self.alkane_id_to_bytes(&staked_alkane_id))
-            .get_value::<u128>();
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
 
-        if staked_at_block == 0 {
-            return Err(anyhow!("Orbital stake record not found"));
-        }
+        let trait_key = self.words_to_trait_key(context.inputs.get(2..).unwrap_or_default())?;
+        self.trait_weights_pointer()
+            .select(&trait_key)
+            .set_value::<u128>(weight);
+
+        Ok(response)
+    }
 
-        let period_blocks = u128::from(self.height()).saturating_sub(staked_at_block);
+    pub fn get_trait_weight(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-        let mut total_staked_blocks_pointer = self
-            .total_staked_blocks_pointer()
-            .select(&self.alkane_id_to_bytes(&staked_alkane_id));
-        let previous_total = total_staked_blocks_pointer.get_value::<u128>();
-        let new_total = previous_total
-            .checked_add(period_blocks)
-            .ok_or_else(|| anyhow!("Total staked blocks overflow"))?;
-        total_staked_blocks_pointer.set_value(new_total);
+        let trait_key = self.words_to_trait_key(context.inputs.get(1..).unwrap_or_default())?;
+        response.data = self
+            .trait_weight(&self.trait_weights_pointer(), &trait_key)
+            .unwrap_or(TRAIT_WEIGHT_BASE)
+            .to_le_bytes()
+            .to_vec();
 
-        // clean the stake height record
-        self.stake_height_pointer()
-            .select(&self.alkane_id_to_bytes(&staked_alkane_id))
-            .set_value(0u128);
+        Ok(response)
+    }
 
-        // Fix: Properly increment the total unstaked counter
-        let total_unstaked_current = self.total_unstaked_pointer().get_value::<u128>();
-        let new_total_unstaked = total_unstaked_current
-            .checked_add(1)
-            .ok_or_else(|| anyhow!("Total unstaked overflow"))?;
-        self.total_unstaked_pointer().set_value(new_total_unstaked);
+    pub fn clear_trait_weight(&self) -> Result<CallResponse> {
+        self.only_admin()?;
 
-        let mut response = CallResponse::default();
-        response.alkanes.0.push(AlkaneTransfer {
-            id: staked_alkane_id,
-            value: 1u128,
-        });
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        let trait_key = self.words_to_trait_key(context.inputs.get(1..).unwrap_or_default())?;
+        self.trait_weights_pointer()
+            .select(&trait_key)
+            .set(Arc::new(Vec::new()));
 
         Ok(response)
     }
 
-    fn create_mint_transfer(&self, index: u128) -> Result<AlkaneTransfer> {
-        let max_total = self.max_mints();
+    /// Set the rarity weights of the overlays (admin only)
+    pub fn set_overlay_weights(
+        &self,
+        blue: u128,
+        green: u128,
+        pink: u128,
+        glitch: u128,
+    ) -> Result<CallResponse> {
+        self.only_admin()?;
 
-        if index >= max_total {
-            return Err(anyhow!("Minted out"));
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        let weights = [blue, green, pink, glitch];
+        weights
+            .iter()
+            .try_fold(0u128, |total, weight| total.checked_add(*weight))
+            .ok_or_else(|| anyhow!("Overlay weights overflow"))?;
+
+        let weights_pointer = self.overlay_weights_pointer();
+        for (overlay, weight) in weights.iter().enumerate() {
+            weights_pointer
+                .select(&(overlay as u128).to_le_bytes().to_vec())
+                .set_value::<u128>(*weight);
         }
 
-        let cellpack = Cellpack {
-            target: AlkaneId {
-                block: 6,
-                tx: ORBITAL_TEMPLATE_ID,
-            },
-            inputs: vec![0x0, index],
-        };
-
-        let sequence = self.sequence();
-        let response = self.call(&cellpack, &AlkaneTransferParcel::default(), self.fuel())?;
-
-        let orbital_id = AlkaneId {
-            block: 2,
-            tx: sequence,
-        };
+        Ok(response)
+    }
 
-        self.add_instance(&orbital_id, &index)?;
+    pub fn get_overlay_weights(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-        if response.alkanes.0.len() < 1 {
-            Err(anyhow!("orbital token not returned with factory"))
-        } else {
-            Ok(response.alkanes.0[0])
+        let mut data = Vec::with_capacity(16 * OVERLAY_TIERS.len());
+        for weight in self.overlay_weights() {
+            data.extend_from_slice(&weight.to_le_bytes());
         }
+
+        response.data = data;
+        Ok(response)
     }
 
-    pub fn get_total_staked_blocks(&self, block: u128, tx: u128) -> Result<CallResponse> {
+    /// Set the global lock and the early-unstake penalty (admin only)
+    pub fn set_lockup(&self, lock_blocks: u128, penalty_bps: u128) -> Result<CallResponse> {
+        self.only_admin()?;
+
         let context = self.context()?;
-        let mut response = CallResponse::forward(&context.incoming_alkanes);
+        let response = CallResponse::forward(&context.incoming_alkanes);
 
-        let alkane_id = AlkaneId { block, tx };
-        if !self.verify_id_collection(&alkane_id) {
-            return Err(anyhow!("Orbital ID not from {}", CONTRACT_NAME));
+        if penalty_bps > PENALTY_BASE {
+            return Err(anyhow!(
+                "Penalty can't exceed {} basis points",
+                PENALTY_BASE
+            ));
         }
 
-        let total_staked_blocks = self
-            .total_staked_blocks_pointer()
-            .select(&self.alkane_id_to_bytes(&alkane_id))
-            .get_value::<u128>();
+        self.lock_blocks_pointer().set_value(lock_blocks);
+        self.penalty_bps_pointer().set_value(penalty_bps);
 
-        response.data = total_staked_blocks.to_le_bytes().to_vec();
         Ok(response)
     }
 
-    fn get_staked_orbital_id_by_lp_id(&self, key: &Vec<u8>) -> Result<AlkaneId> {
-        let pointer = self.staked_id_pointer().select(key);
-        let data = pointer.get();
+    pub fn get_lockup(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let mut data = Vec::with_capacity(48);
+        data.extend_from_slice(&self.lock_blocks_pointer().get_value::<u128>().to_le_bytes());
+        data.extend_from_slice(&self.penalty_bps_pointer().get_value::<u128>().to_le_bytes());
+        data.extend_from_slice(
+            &self
+                .total_forfeited_blocks_pointer()
+                .get_value::<u128>()
+                .to_le_bytes(),
+        );
+
+        response.data = data;
+        Ok(response)
+    }
+
+    /// Set the unbonding period (admin only)
+    pub fn set_unbonding_blocks(&self, blocks: u128) -> Result<CallResponse> {
+        self.only_admin()?;
+
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        self.unbonding_blocks_pointer().set_value(blocks);
+
+        Ok(response)
+    }
+
+    pub fn get_unbonding_blocks(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self
+            .unbonding_blocks_pointer()
+            .get_value::<u128>()
+            .to_le_bytes()
+            .to_vec();
+
+        Ok(response)
+    }
+
+    pub fn backfill_staked_orbital(&self, lp_block: u128, lp_tx: u128) -> Result<CallResponse> {
+        self.only_admin()?;
+
+        let context = self.context()?;
+        let response = CallResponse::forward(&context.incoming_alkanes);
+
+        let lp_id = AlkaneId {
+            block: lp_block,
+            tx: lp_tx,
+        };
+        let orbital_id = self.get_staked_orbital_id_by_lp_id(&self.alkane_id_to_bytes(&lp_id))?;
+        let orbital_id_bytes = self.alkane_id_to_bytes(&orbital_id);
+
+        let staked_at_block = self
+            .stake_height_pointer()
+            .select(&orbital_id_bytes)
+            .get_value::<u128>();
+        if staked_at_block == 0 {
+            return Err(anyhow!(
+                "Orbital {}:{} is not staked",
+                orbital_id.block,
+                orbital_id.tx
+            ));
+        }
+
+        let mut lp_pointer = self.lp_by_orbital_pointer().select(&orbital_id_bytes);
+        if lp_pointer.get().is_empty() {
+            lp_pointer.set(Arc::new(self.alkane_id_to_bytes(&lp_id)));
+        }
+
+        // Orbitals already in the set are counted, so backfilling twice is a no-op
+        if self.add_staked_orbital(&orbital_id)? {
+            let mut staked_heights_sum_pointer = self.staked_heights_sum_pointer();
+            let new_staked_heights_sum = staked_heights_sum_pointer
+                .get_value::<u128>()
+                .checked_add(staked_at_block)
+                .ok_or_else(|| anyhow!("Staked heights sum overflow"))?;
+            staked_heights_sum_pointer.set_value(new_staked_heights_sum);
+        }
+
+        Ok(response)
+    }
+
+    pub fn get_eligible_collections(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let collections_pointer = self.eligible_collections_pointer();
+        let count = collections_pointer.get_value::<u128>();
+
+        let mut data = Vec::new();
+        for i in 0..count {
+            let collection_id = collections_pointer.select(&i.to_le_bytes().to_vec()).get();
+            data.extend_from_slice(&collection_id);
+        }
+
+        response.data = data;
+        Ok(response)
+    }
+
+    pub fn get_orbital_collection(&self, block: u128, tx: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let collection_id = self.orbital_collection_id(&AlkaneId { block, tx })?;
+        response.data = self.alkane_id_to_bytes(&collection_id);
+
+        Ok(response)
+    }
+
+    fn register_collection(&self, collection_id: &AlkaneId) -> Result<()> {
+        let collection_id_bytes = self.alkane_id_to_bytes(collection_id);
+        let mut registered_pointer = self
+            .collection_registered_pointer()
+            .select(&collection_id_bytes);
+
+        if registered_pointer.get_value::<u128>() != 0 {
+            return Err(anyhow!(
+                "Collection {}:{} already registered",
+                collection_id.block,
+                collection_id.tx
+            ));
+        }
+
+        let mut collections_pointer = self.eligible_collections_pointer();
+        let count = collections_pointer.get_value::<u128>();
+        collections_pointer
+            .select(&count.to_le_bytes().to_vec())
+            .set(Arc::new(collection_id_bytes));
+        collections_pointer.set_value(count + 1);
+        registered_pointer.set_value(1u128);
+
+        Ok(())
+    }
+
+    fn set_collection_root(&self, collection_id: &AlkaneId, root: &[u8; 32], leaf_count: u128) {
+        let mut data = Vec::with_capacity(48);
+        data.extend_from_slice(root);
+        data.extend_from_slice(&leaf_count.to_le_bytes());
+
+        self.collection_root_pointer()
+            .select(&self.alkane_id_to_bytes(collection_id))
+            .set(Arc::new(data));
+    }
+
+    /// Find the collection an orbital is proven eligible for, either from a
+    /// previous verification or from one of the given proofs
+    fn prove_eligibility(
+        &self,
+        orbital_id: &AlkaneId,
+        proofs: &[EligibilityProof],
+    ) -> Option<AlkaneId> {
+        if let Ok(collection_id) = self.orbital_collection_id(orbital_id) {
+            return Some(collection_id);
+        }
+
+        let proof = proofs
+            .iter()
+            .find(|proof| proof.orbital_id == *orbital_id)?;
+        let root = self
+            .collection_root_pointer()
+            .select(&self.alkane_id_to_bytes(&proof.collection_id))
+            .get();
+
+        if root.len() != 48 {
+            return None;
+        }
+
+        let leaf_count = u128::from_le_bytes(root[32..48].try_into().unwrap());
+        if proof.verify(root[0..32].try_into().unwrap(), leaf_count) {
+            Some(proof.collection_id)
+        } else {
+            None
+        }
+    }
+
+    /// Remember the collection of an eligible orbital so later calls don't need a proof
+    fn remember_eligibility(&self, orbital_id: &AlkaneId, collection_id: &AlkaneId) {
+        self.eligible_orbitals_pointer()
+            .select(&self.alkane_id_to_bytes(orbital_id))
+            .set(Arc::new(self.alkane_id_to_bytes(collection_id)));
+    }
+
+    /// Get the registered collection an eligible orbital belongs to
+    fn orbital_collection_id(&self, orbital_id: &AlkaneId) -> Result<AlkaneId> {
+        self.orbital_records().collection_id(orbital_id)
+    }
+
+    /// Resolve the upstream collection to read images and attributes from: the
+    /// collection of the orbital behind the LP, or the first registered one
+    fn upstream_collection_id(&self, index: u128) -> Result<AlkaneId> {
+        if let Some((_, orbital_id)) = self.resolve_lp_orbital(index) {
+            return self.orbital_collection_id(&orbital_id);
+        }
+
+        self.default_collection_id()
+    }
+
+    /// The first registered collection, which bare indexes refer to
+    fn default_collection_id(&self) -> Result<AlkaneId> {
+        self.first_collection_id(&self.eligible_collections_pointer())
+    }
+
+    /// Get the first collection of a registry, Beep Boop while it is empty so a
+    /// vault upgraded in place keeps serving data before SeedRegistry is called
+    fn first_collection_id<P: KeyValuePointer>(&self, collections: &P) -> Result<AlkaneId> {
+        let first_collection = collections.select(&0u128.to_le_bytes().to_vec()).get();
+
+        if first_collection.is_empty() {
+            return Ok(BEEP_BOOP_COLLECTION);
+        }
+
+        self.bytes_to_alkane_id(&first_collection)
+    }
+
+    /// Find the LP and orbital a data or attributes call is about: the calling
+    /// LP, or else the LP minted for `index` in the default collection. Returns
+    /// None when neither is known rather than guessing across collections
+    fn resolve_lp_orbital(&self, index: u128) -> Option<(AlkaneId, AlkaneId)> {
+        let context = self.context().ok()?;
+
+        let caller_bytes = self.alkane_id_to_bytes(&context.caller);
+        let lp_id = if !self
+            .staked_id_pointer()
+            .select(&caller_bytes)
+            .get()
+            .is_empty()
+        {
+            context.caller
+        } else {
+            let collection_id = self.default_collection_id().ok()?;
+            self.instance_lp_id(&collection_id, index)?
+        };
+
+        let orbital_id = self
+            .get_staked_orbital_id_by_lp_id(&self.alkane_id_to_bytes(&lp_id))
+            .ok()?;
+
+        Some((lp_id, orbital_id))
+    }
+
+    /// Ensure the call carries the authority token minted at initialize
+    fn only_admin(&self) -> Result<()> {
+        let context = self.context()?;
+
+        if !self.carries_admin_token(&context.incoming_alkanes.0, &context.myself) {
+            return Err(anyhow!("Call must carry the {} admin token", CONTRACT_NAME));
+        }
+
+        Ok(())
+    }
+
+    /// The admin token is the contract's own alkane, minted once by Initialize
+    fn carries_admin_token(&self, incoming: &[AlkaneTransfer], myself: &AlkaneId) -> bool {
+        incoming
+            .iter()
+            .any(|alkane| alkane.id == *myself && alkane.value >= 1)
+    }
+
+    fn stake_paused(&self) -> bool {
+        self.stake_paused_pointer().get_value::<u128>() != 0
+    }
+
+    fn unstake_paused(&self) -> bool {
+        self.unstake_paused_pointer().get_value::<u128>() != 0
+    }
+
+    fn ensure_not_paused(&self, action: VaultAction) -> Result<()> {
+        self.check_pause(action, self.stake_paused(), self.unstake_paused())
+    }
+
+    /// Calls that put orbitals into the vault follow the stake switch, calls
+    /// that take them out follow the unstake switch
+    fn check_pause(
+        &self,
+        action: VaultAction,
+        stake_paused: bool,
+        unstake_paused: bool,
+    ) -> Result<()> {
+        match action {
+            VaultAction::Stake | VaultAction::CancelUnstake if stake_paused => {
+                Err(anyhow!("Staking is paused"))
+            }
+            VaultAction::Unstake | VaultAction::RequestUnstake | VaultAction::CompleteUnstake
+                if unstake_paused =>
+            {
+                Err(anyhow!("Unstaking is paused"))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn get_name(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.name().into_bytes();
+
+        Ok(response)
+    }
+
+    pub fn get_symbol(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.symbol().into_bytes();
+
+        Ok(response)
+    }
+
+    /// Public stake function for orbitals
+    pub fn stake(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+
+        // inputs: opcode, then eligibility proofs
+        let proofs = EligibilityProof::parse_all(context.inputs.get(1..).unwrap_or_default())?;
+
+        self.stake_orbitals(0, &proofs)
+    }
+
+    /// Stake orbitals with a minimum lock chosen by the staker
+    pub fn stake_locked(&self, lock_blocks: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+
+        // inputs: opcode, lock blocks, then eligibility proofs
+        let proofs = EligibilityProof::parse_all(context.inputs.get(2..).unwrap_or_default())?;
+
+        self.stake_orbitals(lock_blocks, &proofs)
+    }
+
+    fn stake_orbitals(
+        &self,
+        lock_blocks: u128,
+        proofs: &[EligibilityProof],
+    ) -> Result<CallResponse> {
+        let context = self.context()?;
+
+        self.ensure_not_paused(VaultAction::Stake)?;
+
+        // Only eligible orbitals are staked, anything else sent along (the admin
+        // token included) is handed back
+        let orbitals = self.stakeable_orbitals(&context.incoming_alkanes.0, proofs)?;
+        if orbitals.is_empty() {
+            return Err(anyhow!("Must send at least 1 orbital to stake"));
+        }
+
+        // The global lock is a floor for every stake
+        let lock_blocks = lock_blocks.max(self.lock_blocks_pointer().get_value::<u128>());
+        let unlock_height = self.unlock_height(u128::from(self.height()), lock_blocks)?;
+
+        for (alkane, collection_id) in &orbitals {
+            self.remember_eligibility(&alkane.id, collection_id);
+            let key = self.alkane_id_to_bytes(&alkane.id);
+            if self.stake_height_pointer().select(&key).get_value::<u128>() != 0 {
+                return Err(anyhow!(
+                    "Orbital {}:{} already staked",
+                    alkane.id.block,
+                    alkane.id.tx
+                ));
+            }
+        }
+
+        let mut minted_lp_orbitals = Vec::new();
+        let mut newly_staked_count = 0u128;
+
+        for (alkane, collection_id) in &orbitals {
+            if alkane.value != 1 {
+                return Err(anyhow!("Alkane amount must be 1"));
+            }
+
+            let index = self.orbital_index(&alkane.id)?;
+            let collection_id = *collection_id;
+
+            // Set stake block pointer for this alkane
+            self.stake_height_pointer()
+                .select(&self.alkane_id_to_bytes(&alkane.id))
+                .set_value(u128::from(self.height())); // this should be u128
+
+            self.unlock_height_pointer()
+                .select(&self.alkane_id_to_bytes(&alkane.id))
+                .set_value(unlock_height);
+
+            // LPs are looked up by orbital, then by collection and index for LPs
+            // minted before the orbital mapping existed
+            let minted_lp_id = self
+                .bytes_to_alkane_id(
+                    &self
+                        .lp_by_orbital_pointer()
+                        .select(&self.alkane_id_to_bytes(&alkane.id))
+                        .get(),
+                )
+                .ok()
+                .or_else(|| self.instance_lp_id(&collection_id, index));
+
+            let lp_transfer = if let Some(existing_alkane_id) = minted_lp_id {
+                AlkaneTransfer {
+                    id: existing_alkane_id,
+                    value: 1u128,
+                }
+            } else {
+                let minted = self.create_mint_transfer(&collection_id, index)?;
+
+                let mut staked_pointer = self
+                    .staked_id_pointer()
+                    .select(&self.alkane_id_to_bytes(&minted.id));
+
+                staked_pointer.set(Arc::new(self.alkane_id_to_bytes(&alkane.id)));
+
+                self.record_event(EventKind::LpMinted, &alkane.id, &minted.id, 1)?;
+
+                minted
+            };
+
+            self.lp_by_orbital_pointer()
+                .select(&self.alkane_id_to_bytes(&alkane.id))
+                .set(Arc::new(self.alkane_id_to_bytes(&lp_transfer.id)));
+
+            self.open_stake_session(&alkane.id, &lp_transfer.id)?;
+            self.add_staked_orbital(&alkane.id)?;
+            self.record_event(EventKind::Staked, &alkane.id, &lp_transfer.id, lock_blocks)?;
+
+            minted_lp_orbitals.push(lp_transfer);
+            newly_staked_count += 1;
+        }
+
+        // Increment total staked count only by newly staked orbitals
+        let mut total_staked_pointer = self.total_staked_pointer();
+        let current_total = total_staked_pointer.get_value::<u128>();
+        total_staked_pointer.set_value(current_total + newly_staked_count);
+
+        // Every orbital in this call was staked at the current height
+        let mut staked_heights_sum_pointer = self.staked_heights_sum_pointer();
+        let new_staked_heights_sum = u128::from(self.height())
+            .checked_mul(newly_staked_count)
+            .and_then(|added| added.checked_add(staked_heights_sum_pointer.get_value::<u128>()))
+            .ok_or_else(|| anyhow!("Staked heights sum overflow"))?;
+        staked_heights_sum_pointer.set_value(new_staked_heights_sum);
+
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+        response
+            .alkanes
+            .0
+            .retain(|alkane| !orbitals.iter().any(|(orbital, _)| orbital.id == alkane.id));
+        response.alkanes.0.extend(minted_lp_orbitals);
+
+        Ok(response)
+    }
+
+    /// Pick the alkanes of a stake call that are eligible orbitals, with their
+    /// collection. An alkane with a proof in the call must verify, so a bad
+    /// proof fails the call instead of silently handing the orbital back.
+    fn stakeable_orbitals(
+        &self,
+        incoming: &[AlkaneTransfer],
+        proofs: &[EligibilityProof],
+    ) -> Result<Vec<(AlkaneTransfer, AlkaneId)>> {
+        let mut orbitals = Vec::new();
+
+        for alkane in incoming {
+            if let Some(collection_id) = self.prove_eligibility(&alkane.id, proofs) {
+                let orbital = AlkaneTransfer {
+                    id: alkane.id,
+                    value: alkane.value,
+                };
+                orbitals.push((orbital, collection_id));
+            } else if proofs.iter().any(|proof| proof.orbital_id == alkane.id) {
+                return Err(anyhow!(
+                    "Orbital {}:{} not verified: missing or invalid eligibility proof",
+                    alkane.id.block,
+                    alkane.id.tx
+                ));
+            }
+        }
+
+        Ok(orbitals)
+    }
+
+    pub fn unstake(&self) -> Result<CallResponse> {
+        self.ensure_not_paused(VaultAction::Unstake)?;
+
+        if self.unbonding_blocks_pointer().get_value::<u128>() != 0 {
+            return Err(anyhow!("Unbonding is enabled, use RequestUnstake"));
+        }
+
+        let staked_alkane_ids = self.incoming_lp_orbitals()?;
+        for staked_alkane_id in &staked_alkane_ids {
+            self.ensure_staked(staked_alkane_id)?;
+        }
+
+        let context = self.context()?;
+        let mut response = CallResponse::default();
+
+        for (staked_alkane_id, lp) in staked_alkane_ids.iter().zip(&context.incoming_alkanes.0) {
+            let credited_blocks = self.release_orbital(staked_alkane_id)?;
+            self.record_event(
+                EventKind::Unstaked,
+                staked_alkane_id,
+                &lp.id,
+                credited_blocks,
+            )?;
+
+            response.alkanes.0.push(AlkaneTransfer {
+                id: *staked_alkane_id,
+                value: 1u128,
+            });
+        }
+
+        self.add_total_unstaked(staked_alkane_ids.len() as u128)?;
+
+        Ok(response)
+    }
+
+    /// Stop accrual on the orbitals behind the LP tokens sent and start their unbonding
+    pub fn request_unstake(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+
+        self.ensure_not_paused(VaultAction::RequestUnstake)?;
+
+        let staked_alkane_ids = self.incoming_lp_orbitals()?;
+        for staked_alkane_id in &staked_alkane_ids {
+            self.ensure_staked(staked_alkane_id)?;
+        }
+
+        let height = u128::from(self.height());
+        let unbonding_blocks = self.unbonding_blocks_pointer().get_value::<u128>();
+
+        for (staked_alkane_id, lp) in staked_alkane_ids.iter().zip(&context.incoming_alkanes.0) {
+            let alkane_id_bytes = self.alkane_id_to_bytes(staked_alkane_id);
+
+            // Releasing clears the lock, so keep it with the request for a cancel
+            let unlock_height = self
+                .unlock_height_pointer()
+                .select(&alkane_id_bytes)
+                .get_value::<u128>();
+            let credited_blocks = self.release_orbital(staked_alkane_id)?;
+            self.hold_unbonding(
+                &self.unbonding_request_pointer(),
+                staked_alkane_id,
+                unlock_height,
+                credited_blocks,
+            );
+
+            self.unbonding_height_pointer()
+                .select(&alkane_id_bytes)
+                .set_value(height);
+
+            self.record_event(
+                EventKind::UnstakeRequested,
+                staked_alkane_id,
+                &lp.id,
+                self.unbonding_ready_height(height, unbonding_blocks),
+            )?;
+        }
+
+        // the LP tokens stay with the staker until the unbonding completes
+        Ok(CallResponse::forward(&context.incoming_alkanes))
+    }
+
+    /// Hand back orbitals whose unbonding period is over, consuming their LP tokens
+    pub fn complete_unstake(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+
+        self.ensure_not_paused(VaultAction::CompleteUnstake)?;
+
+        let orbital_ids = self.incoming_lp_orbitals()?;
+        let unbonding_blocks = self.unbonding_blocks_pointer().get_value::<u128>();
+        let height = u128::from(self.height());
+
+        let mut response = CallResponse::default();
+
+        for (orbital_id, lp) in orbital_ids.iter().zip(&context.incoming_alkanes.0) {
+            let request_height = self.unbonding_request_height(orbital_id)?;
+            self.ensure_unbonded(orbital_id, request_height, unbonding_blocks, height)?;
+
+            self.unbonding_height_pointer()
+                .select(&self.alkane_id_to_bytes(orbital_id))
+                .set_value(0u128);
+            let (_, credited_blocks) =
+                self.take_unbonding(&self.unbonding_request_pointer(), orbital_id);
+
+            self.record_event(
+                EventKind::UnstakeCompleted,
+                orbital_id,
+                &lp.id,
+                height - request_height,
+            )?;
+            self.record_event(EventKind::Unstaked, orbital_id, &lp.id, credited_blocks)?;
+
+            response.alkanes.0.push(AlkaneTransfer {
+                id: *orbital_id,
+                value: 1u128,
+            });
+        }
+
+        self.add_total_unstaked(orbital_ids.len() as u128)?;
+
+        Ok(response)
+    }
+
+    /// Put unbonding orbitals back into staking with a new session on the same LP
+    pub fn cancel_unstake(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+
+        self.ensure_not_paused(VaultAction::CancelUnstake)?;
+
+        let orbital_ids = self.incoming_lp_orbitals()?;
+        for orbital_id in &orbital_ids {
+            self.unbonding_request_height(orbital_id)?;
+        }
+
+        let height = u128::from(self.height());
+
+        for (orbital_id, lp) in orbital_ids.iter().zip(&context.incoming_alkanes.0) {
+            let orbital_id_bytes = self.alkane_id_to_bytes(orbital_id);
+
+            // The orbital goes back under the lock it had before the request
+            let (unlock_height, _) =
+                self.take_unbonding(&self.unbonding_request_pointer(), orbital_id);
+
+            self.unbonding_height_pointer()
+                .select(&orbital_id_bytes)
+                .set_value(0u128);
+            self.stake_height_pointer()
+                .select(&orbital_id_bytes)
+                .set_value(height);
+            self.unlock_height_pointer()
+                .select(&orbital_id_bytes)
+                .set_value(unlock_height);
+
+            self.open_stake_session(orbital_id, &lp.id)?;
+            self.add_staked_orbital(orbital_id)?;
+            self.record_event(
+                EventKind::Staked,
+                orbital_id,
+                &lp.id,
+                unlock_height.saturating_sub(height),
+            )?;
+        }
+
+        let restaked_count = orbital_ids.len() as u128;
+
+        let mut total_staked_pointer = self.total_staked_pointer();
+        let new_total_staked = total_staked_pointer
+            .get_value::<u128>()
+            .checked_add(restaked_count)
+            .ok_or_else(|| anyhow!("Total staked overflow"))?;
+        total_staked_pointer.set_value(new_total_staked);
+
+        let mut staked_heights_sum_pointer = self.staked_heights_sum_pointer();
+        let new_staked_heights_sum = height
+            .checked_mul(restaked_count)
+            .and_then(|added| added.checked_add(staked_heights_sum_pointer.get_value::<u128>()))
+            .ok_or_else(|| anyhow!("Staked heights sum overflow"))?;
+        staked_heights_sum_pointer.set_value(new_staked_heights_sum);
+
+        Ok(CallResponse::forward(&context.incoming_alkanes))
+    }
+
+    /// Resolve the orbitals behind the LP tokens sent, before touching storage so
+    /// the whole call fails if any of them is unknown
+    fn incoming_lp_orbitals(&self) -> Result<Vec<AlkaneId>> {
+        let context = self.context()?;
+
+        self.map_lp_orbitals(&self.staked_id_pointer(), &context.incoming_alkanes.0)
+    }
+
+    /// Map LP transfers to the orbitals they were minted for, failing on the
+    /// first unknown, duplicate or partial LP
+    fn map_lp_orbitals<P: KeyValuePointer>(
+        &self,
+        staked_ids: &P,
+        lps: &[AlkaneTransfer],
+    ) -> Result<Vec<AlkaneId>> {
+        if lps.is_empty() {
+            return Err(anyhow!("Must send at least 1 LP token"));
+        }
+
+        let mut orbital_ids = Vec::with_capacity(lps.len());
+
+        for alkane in lps {
+            if alkane.value != 1 {
+                return Err(anyhow!("LP token amount must be 1"));
+            }
+
+            let orbital_id =
+                self.lookup_staked_orbital(staked_ids, &self.alkane_id_to_bytes(&alkane.id))?;
+
+            if orbital_ids.contains(&orbital_id) {
+                return Err(anyhow!(
+                    "LP token {}:{} sent more than once",
+                    alkane.id.block,
+                    alkane.id.tx
+                ));
+            }
+
+            orbital_ids.push(orbital_id);
+        }
+
+        Ok(orbital_ids)
+    }
+
+    fn ensure_staked(&self, orbital_id: &AlkaneId) -> Result<()> {
+        let staked_at_block = self
+            .stake_height_pointer()
+            .select(&self.alkane_id_to_bytes(orbital_id))
+            .get_value::<u128>();
+
+        if staked_at_block == 0 {
+            return Err(anyhow!(
+                "Orbital {}:{} stake record not found",
+                orbital_id.block,
+                orbital_id.tx
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Get the height unbonding was requested at, failing if the orbital is not unbonding
+    fn unbonding_request_height(&self, orbital_id: &AlkaneId) -> Result<u128> {
+        let request_height = self
+            .unbonding_height_pointer()
+            .select(&self.alkane_id_to_bytes(orbital_id))
+            .get_value::<u128>();
+
+        self.check_unbonding(orbital_id, request_height)
+    }
+
+    /// Fail if no unbonding was requested, 0 being the unset request height
+    fn check_unbonding(&self, orbital_id: &AlkaneId, request_height: u128) -> Result<u128> {
+        if request_height == 0 {
+            return Err(anyhow!(
+                "Orbital {}:{} is not unbonding",
+                orbital_id.block,
+                orbital_id.tx
+            ));
+        }
+
+        Ok(request_height)
+    }
+
+    /// Get the height an unbonding orbital can be withdrawn at, 0 when not unbonding
+    fn unbonding_ready_height(&self, request_height: u128, unbonding_blocks: u128) -> u128 {
+        if request_height == 0 {
+            return 0;
+        }
+
+        request_height.saturating_add(unbonding_blocks)
+    }
+
+    /// Fail unless the unbonding period requested at `request_height` is over at `height`
+    fn ensure_unbonded(
+        &self,
+        orbital_id: &AlkaneId,
+        request_height: u128,
+        unbonding_blocks: u128,
+        height: u128,
+    ) -> Result<()> {
+        self.check_unbonding(orbital_id, request_height)?;
+
+        let ready_height = self.unbonding_ready_height(request_height, unbonding_blocks);
+        if height < ready_height {
+            return Err(anyhow!(
+                "Orbital {}:{} is unbonding until block {}",
+                orbital_id.block,
+                orbital_id.tx,
+                ready_height
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Keep the unlock height and the credited blocks of an orbital with its
+    /// unbonding request
+    fn hold_unbonding<P: KeyValuePointer>(
+        &self,
+        requests: &P,
+        orbital_id: &AlkaneId,
+        unlock_height: u128,
+        credited_blocks: u128,
+    ) {
+        let mut request = Vec::with_capacity(32);
+        request.extend_from_slice(&unlock_height.to_le_bytes());
+        request.extend_from_slice(&credited_blocks.to_le_bytes());
+
+        requests
+            .select(&self.alkane_id_to_bytes(orbital_id))
+            .set(Arc::new(request));
+    }
+
+    /// Take the unlock height and the credited blocks held with an unbonding
+    /// request, zeros for requests made before they were held
+    fn take_unbonding<P: KeyValuePointer>(
+        &self,
+        requests: &P,
+        orbital_id: &AlkaneId,
+    ) -> (u128, u128) {
+        let mut request_pointer = requests.select(&self.alkane_id_to_bytes(orbital_id));
+        let request = request_pointer.get();
+        if request.len() != 32 {
+            return (0, 0);
+        }
+
+        request_pointer.set(Arc::new(Vec::new()));
+        (
+            u128::from_le_bytes(request[0..16].try_into().unwrap()),
+            u128::from_le_bytes(request[16..32].try_into().unwrap()),
+        )
+    }
+
+    fn add_total_unstaked(&self, count: u128) -> Result<()> {
+        let mut total_unstaked_pointer = self.total_unstaked_pointer();
+        let new_total_unstaked = total_unstaked_pointer
+            .get_value::<u128>()
+            .checked_add(count)
+            .ok_or_else(|| anyhow!("Total unstaked overflow"))?;
+        total_unstaked_pointer.set_value(new_total_unstaked);
+
+        Ok(())
+    }
+
+    /// Get the height a stake made at `height` unlocks at, 0 when there is no lock
+    fn unlock_height(&self, height: u128, lock_blocks: u128) -> Result<u128> {
+        if lock_blocks == 0 {
+            return Ok(0);
+        }
+
+        height
+            .checked_add(lock_blocks)
+            .ok_or_else(|| anyhow!("Unlock height overflow"))
+    }
+
+    /// Get the blocks of a session forfeited by unstaking at `height`, 0 once
+    /// the unlock height is reached
+    fn forfeited_blocks(
+        &self,
+        period_blocks: u128,
+        height: u128,
+        unlock_height: u128,
+        penalty_bps: u128,
+    ) -> Result<u128> {
+        if height >= unlock_height {
+            return Ok(0);
+        }
+
+        Ok(period_blocks
+            .checked_mul(penalty_bps)
+            .ok_or_else(|| anyhow!("Forfeited blocks overflow"))?
+            / PENALTY_BASE)
+    }
+
+    /// Close the current stake period of an orbital and add it to its total
+    /// staked blocks, returning the blocks credited for the period
+    fn release_orbital(&self, staked_alkane_id: &AlkaneId) -> Result<u128> {
+        let alkane_id_bytes = self.alkane_id_to_bytes(staked_alkane_id);
+
+        let staked_at_block = self
+            .stake_height_pointer()
+            .select(&alkane_id_bytes)
+            .get_value::<u128>();
+
+        let height = u128::from(self.height());
+        let mut period_blocks = height.saturating_sub(staked_at_block);
+
+        // Unstaking before the lock ends forfeits part of the session
+        let mut unlock_height_pointer = self.unlock_height_pointer().select(&alkane_id_bytes);
+        let forfeited_blocks = self.forfeited_blocks(
+            period_blocks,
+            height,
+            unlock_height_pointer.get_value::<u128>(),
+            self.penalty_bps_pointer().get_value::<u128>(),
+        )?;
+        if forfeited_blocks != 0 {
+            period_blocks -= forfeited_blocks;
+
+            let mut forfeited_pointer = self.forfeited_blocks_pointer().select(&alkane_id_bytes);
+            let new_forfeited = forfeited_pointer
+                .get_value::<u128>()
+                .checked_add(forfeited_blocks)
+                .ok_or_else(|| anyhow!("Forfeited blocks overflow"))?;
+            forfeited_pointer.set_value(new_forfeited);
+
+            let mut total_forfeited_pointer = self.total_forfeited_blocks_pointer();
+            let new_total_forfeited = total_forfeited_pointer
+                .get_value::<u128>()
+                .checked_add(forfeited_blocks)
+                .ok_or_else(|| anyhow!("Total forfeited blocks overflow"))?;
+            total_forfeited_pointer.set_value(new_total_forfeited);
+        }
+        unlock_height_pointer.set_value(0u128);
+
+        let mut total_staked_blocks_pointer =
+            self.total_staked_blocks_pointer().select(&alkane_id_bytes);
+        let previous_total = total_staked_blocks_pointer.get_value::<u128>();
+        let new_total = previous_total
+            .checked_add(period_blocks)
+            .ok_or_else(|| anyhow!("Total staked blocks overflow"))?;
+        total_staked_blocks_pointer.set_value(new_total);
+
+        let mut total_accrued_blocks_pointer = self.total_accrued_blocks_pointer();
+        let new_total_accrued = total_accrued_blocks_pointer
+            .get_value::<u128>()
+            .checked_add(period_blocks)
+            .ok_or_else(|| anyhow!("Total accrued blocks overflow"))?;
+        total_accrued_blocks_pointer.set_value(new_total_accrued);
+
+        self.close_stake_session(staked_alkane_id)?;
+
+        // Orbitals staked before the staked set existed were never added to the
+        // heights sum, unless BackfillStakedOrbital tracked them since
+        if self.remove_staked_orbital(staked_alkane_id)? {
+            let mut staked_heights_sum_pointer = self.staked_heights_sum_pointer();
+            let staked_heights_sum = staked_heights_sum_pointer.get_value::<u128>();
+            staked_heights_sum_pointer
+                .set_value(staked_heights_sum.saturating_sub(staked_at_block));
+        }
+
+        // clean the stake height record
+        self.stake_height_pointer()
+            .select(&alkane_id_bytes)
+            .set_value(0u128);
+
+        Ok(period_blocks)
+    }
+
+    /// Append an event about an orbital and its LP to the event log
+    fn record_event(
+        &self,
+        kind: EventKind,
+        orbital_id: &AlkaneId,
+        lp_id: &AlkaneId,
+        amount: u128,
+    ) -> Result<()> {
+        events::append_event(
+            &mut self.events_pointer(),
+            &Event {
+                kind,
+                height: u128::from(self.height()),
+                subject: *orbital_id,
+                counterpart: *lp_id,
+                amount,
+            },
+        )?;
+
+        Ok(())
+    }
+
+    pub fn get_events(&self, start: u128, limit: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = events::encode_events(&self.events_pointer(), start, limit);
+        Ok(response)
+    }
+
+    /// Append a new open session to the stake history of an orbital
+    fn open_stake_session(&self, orbital_id: &AlkaneId, lp_id: &AlkaneId) -> Result<()> {
+        let mut sessions_pointer = self
+            .stake_sessions_pointer()
+            .select(&self.alkane_id_to_bytes(orbital_id));
+        let count = sessions_pointer.get_value::<u128>();
+
+        let session = self.encode_stake_session(u128::from(self.height()), 0, lp_id);
+
+        sessions_pointer
+            .select(&count.to_le_bytes().to_vec())
+            .set(Arc::new(session));
+
+        let new_count = count
+            .checked_add(1)
+            .ok_or_else(|| anyhow!("Stake sessions overflow"))?;
+        sessions_pointer.set_value(new_count);
+
+        Ok(())
+    }
+
+    /// Encode a stake session as start height, end height (0 while open) and LP id
+    fn encode_stake_session(&self, start: u128, end: u128, lp_id: &AlkaneId) -> Vec<u8> {
+        let mut session = Vec::with_capacity(STAKE_SESSION_SIZE);
+        session.extend_from_slice(&start.to_le_bytes());
+        session.extend_from_slice(&end.to_le_bytes());
+        session.extend_from_slice(&self.alkane_id_to_bytes(lp_id));
+        session
+    }
+
+    /// Clamp a page request to the entries that exist, at most MAX_PAGE_SIZE of them
+    fn page_range(&self, offset: u128, limit: u128, count: u128) -> Range<u128> {
+        let end = offset.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+        offset.min(end)..end
+    }
+
+    /// Record the end height on the latest session of an orbital
+    fn close_stake_session(&self, orbital_id: &AlkaneId) -> Result<()> {
+        let sessions_pointer = self
+            .stake_sessions_pointer()
+            .select(&self.alkane_id_to_bytes(orbital_id));
+        let count = sessions_pointer.get_value::<u128>();
+
+        // Nothing to close if the orbital was staked before session tracking existed
+        if count == 0 {
+            return Ok(());
+        }
+
+        let mut session_pointer = sessions_pointer.select(&(count - 1).to_le_bytes().to_vec());
+        let mut session = session_pointer.get().to_vec();
+
+        if session.len() != STAKE_SESSION_SIZE {
+            return Err(anyhow!("Invalid stake session data"));
+        }
+
+        session[16..32].copy_from_slice(&u128::from(self.height()).to_le_bytes());
+        session_pointer.set(Arc::new(session));
+
+        Ok(())
+    }
+
+    /// Add an orbital to the set of currently staked orbitals, returning false
+    /// if it was already there
+    fn add_staked_orbital(&self, orbital_id: &AlkaneId) -> Result<bool> {
+        self.staked_orbitals()
+            .insert(&self.alkane_id_to_bytes(orbital_id))
+    }
+
+    /// Remove an orbital from the set of currently staked orbitals, returning
+    /// false if it was staked before the set existed
+    fn remove_staked_orbital(&self, orbital_id: &AlkaneId) -> Result<bool> {
+        self.staked_orbitals()
+            .remove(&self.alkane_id_to_bytes(orbital_id))
+    }
+
+    fn create_mint_transfer(
+        &self,
+        collection_id: &AlkaneId,
+        index: u128,
+    ) -> Result<AlkaneTransfer> {
+        let max_total = self.max_mints();
+
+        if index >= max_total {
+            return Err(anyhow!("Minted out"));
+        }
+
+        let cellpack = Cellpack {
+            target: AlkaneId {
+                block: 6,
+                tx: ORBITAL_TEMPLATE_ID,
+            },
+            inputs: vec![0x0, index],
+        };
+
+        let sequence = self.sequence();
+        let response = self.call(&cellpack, &AlkaneTransferParcel::default(), self.fuel())?;
+
+        let orbital_id = AlkaneId {
+            block: 2,
+            tx: sequence,
+        };
+
+        self.add_instance(&orbital_id, collection_id, index)?;
+
+        if response.alkanes.0.len() < 1 {
+            Err(anyhow!("orbital token not returned with factory"))
+        } else {
+            Ok(response.alkanes.0[0])
+        }
+    }
+
+    pub fn get_total_staked_blocks(&self, block: u128, tx: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let alkane_id = AlkaneId { block, tx };
+        let total_staked_blocks = self
+            .total_staked_blocks_pointer()
+            .select(&self.alkane_id_to_bytes(&alkane_id))
+            .get_value::<u128>();
+
+        response.data = total_staked_blocks.to_le_bytes().to_vec();
+        Ok(response)
+    }
+
+    fn get_staked_orbital_id_by_lp_id(&self, key: &Vec<u8>) -> Result<AlkaneId> {
+        self.lookup_staked_orbital(&self.staked_id_pointer(), key)
+    }
+
+    /// Get the orbital an LP id was minted for in an LP to orbital mapping
+    fn lookup_staked_orbital<P: KeyValuePointer>(
+        &self,
+        staked_ids: &P,
+        key: &Vec<u8>,
+    ) -> Result<AlkaneId> {
+        let data = staked_ids.select(key).get();
+
+        if data.len() == 0 {
+            return Err(anyhow!("Beep Boop LP ID not found"));
+        }
+
+        let bytes = data.as_ref();
+
+        let alkane_id = AlkaneId {
+            block: u128::from_le_bytes(bytes[0..16].try_into().unwrap()),
+            tx: u128::from_le_bytes(bytes[16..32].try_into().unwrap()),
+        };
+
+        Ok(alkane_id)
+    }
+
+    fn get_staked_by_lp(&self, block: u128, tx: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let pointer = self
+            .staked_id_pointer()
+            .select(&self.alkane_id_to_bytes(&AlkaneId { block, tx }));
+        let arc_bytes = pointer.get();
+
+        if arc_bytes.len() == 0 {
+            return Err(anyhow!("LP token has no staked orbital"));
+        }
+
+        let alkane_id_string = format!(
+            "{}:{}",
+            u128::from_le_bytes(arc_bytes[0..16].try_into().unwrap()),
+            u128::from_le_bytes(arc_bytes[16..32].try_into().unwrap())
+        );
+
+        response.data = alkane_id_string.into_bytes();
+
+        Ok(response)
+    }
+
+    fn get_lp_by_orbital(&self, block: u128, tx: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let alkane_id = AlkaneId { block, tx };
+        let alkane_id_bytes = self.alkane_id_to_bytes(&alkane_id);
+        let lp_id_bytes = self.lp_by_orbital_pointer().select(&alkane_id_bytes).get();
+
+        let currently_staked = self
+            .stake_height_pointer()
+            .select(&alkane_id_bytes)
+            .get_value::<u128>()
+            != 0;
+
+        response.data = self.encode_lp_lookup(&lp_id_bytes, currently_staked);
+        Ok(response)
+    }
+
+    /// Encode the LP bound to an orbital (zeros if none was ever minted)
+    /// followed by the "ever minted" and "currently staked" flags
+    fn encode_lp_lookup(&self, lp_id_bytes: &[u8], currently_staked: bool) -> Vec<u8> {
+        let ever_minted = lp_id_bytes.len() == 32;
+
+        let mut data = if ever_minted {
+            lp_id_bytes.to_vec()
+        } else {
+            vec![0u8; 32]
+        };
+        data.push(ever_minted as u8);
+        data.push(currently_staked as u8);
+        data
+    }
+
+    fn get_position(&self, block: u128, tx: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let records = self.orbital_records();
+        let orbital_id = records.resolve(&AlkaneId { block, tx })?;
+
+        // The claim contract counts the open session only when asked with the LP
+        let rewards_id = match records.lp_id(&orbital_id) {
+            Some(lp_id) if records.stake_height(&orbital_id) != 0 => lp_id,
+            _ => orbital_id,
+        };
+
+        let position = records.position(
+            &orbital_id,
+            self.orbital_index(&orbital_id).unwrap_or(0),
+            u128::from(self.height()),
+            self.pending_rewards(&rewards_id),
+        );
+
+        response.data = position.encode();
+        debug_assert_eq!(response.data.len(), POSITION_SIZE);
+        Ok(response)
+    }
+
+    /// Ask the claim contract for the $BB an orbital or LP id can claim now,
+    /// 0 when no claim contract is set or it has nothing for the id
+    fn pending_rewards(&self, alkane_id: &AlkaneId) -> u128 {
+        let claim_contract_id = match self.bytes_to_alkane_id(&self.claim_contract_pointer().get())
+        {
+            Ok(claim_contract_id) => claim_contract_id,
+            Err(_) => return 0,
+        };
+
+        let cellpack = Cellpack {
+            target: claim_contract_id,
+            inputs: vec![CLAIM_GET_AVAILABLE_TO_CLAIM, alkane_id.block, alkane_id.tx],
+        };
+
+        self.staticcall(&cellpack, &AlkaneTransferParcel::default(), self.fuel())
+            .ok()
+            .and_then(|response| response.data.try_into().ok())
+            .map(u128::from_le_bytes)
+            .unwrap_or(0)
+    }
+
+    fn get_staked_by_lp_binary(&self, block: u128, tx: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let staked_alkane_id =
+            self.get_staked_orbital_id_by_lp_id(&self.alkane_id_to_bytes(&AlkaneId { block, tx }))?;
+        response.data = self.alkane_id_to_bytes(&staked_alkane_id);
+
+        Ok(response)
+    }
+
+    fn get_rewards(&self, block: u128, tx: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let staked_alkane_id = self.resolve_orbital_id(&AlkaneId { block, tx })?;
+
+        let rewards = self.accrued_staked_blocks(&staked_alkane_id)?;
+
+        response.data = rewards.to_le_bytes().to_vec();
+
+        Ok(response)
+    }
+
+    /// Get the accrued staked blocks of an orbital or LP, scaled by the trait multiplier
+    fn get_weighted_rewards(&self, block: u128, tx: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let orbital_id = self.resolve_orbital_id(&AlkaneId { block, tx })?;
+        let multiplier = self.trait_multiplier(&orbital_id)?;
+        let weighted_rewards = self
+            .accrued_staked_blocks(&orbital_id)?
+            .checked_mul(multiplier)
+            .ok_or_else(|| anyhow!("Weighted rewards overflow"))?
+            / TRAIT_WEIGHT_BASE;
+
+        let mut data = Vec::with_capacity(32);
+        data.extend_from_slice(&weighted_rewards.to_le_bytes());
+        data.extend_from_slice(&multiplier.to_le_bytes());
+
+        response.data = data;
+        Ok(response)
+    }
+
+    /// Get the reward multiplier of an orbital in basis points: the product of
+    /// the weights of its traits, read from the orbital's attributes
+    fn trait_multiplier(&self, orbital_id: &AlkaneId) -> Result<u128> {
+        // Traits live on the base collection, the orbital only knows its index
+        let cellpack = Cellpack {
+            target: self.orbital_collection_id(orbital_id)?,
+            inputs: vec![1002, self.orbital_index(orbital_id)?],
+        };
+        let attributes =
+            self.staticcall(&cellpack, &AlkaneTransferParcel::default(), self.fuel())?;
+
+        let traits = self.parse_traits(&attributes.data);
+        self.weigh_traits(&self.trait_weights_pointer(), &traits)
+    }
+
+    /// Read `trait_type`/`value` pairs from attributes JSON, either an object of
+    /// traits or a list of `trait_type`/`value` entries
+    fn parse_traits(&self, attributes: &[u8]) -> Vec<(String, String)> {
+        match serde_json::from_slice::<Value>(attributes) {
+            Ok(Value::Object(attributes)) => attributes
+                .into_iter()
+                .map(|(trait_type, value)| (trait_type, self.trait_value_string(&value)))
+                .collect(),
+            Ok(Value::Array(attributes)) => attributes
+                .iter()
+                .filter_map(|attribute| {
+                    let trait_type = attribute.get("trait_type")?.as_str()?;
+                    let value = attribute.get("value")?;
+                    Some((trait_type.to_string(), self.trait_value_string(value)))
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Multiply the stored weights of each trait, unset traits counting as 1x
+    fn weigh_traits<P: KeyValuePointer>(
+        &self,
+        trait_weights: &P,
+        traits: &[(String, String)],
+    ) -> Result<u128> {
+        let mut multiplier = TRAIT_WEIGHT_BASE;
+        for (trait_type, value) in traits {
+            let mut trait_key = trait_type.clone().into_bytes();
+            trait_key.push(0);
+            trait_key.extend_from_slice(value.as_bytes());
+
+            multiplier = multiplier
+                .checked_mul(
+                    self.trait_weight(trait_weights, &trait_key)
+                        .unwrap_or(TRAIT_WEIGHT_BASE),
+                )
+                .ok_or_else(|| anyhow!("Trait multiplier overflow"))?
+                / TRAIT_WEIGHT_BASE;
+        }
 
-        if data.len() == 0 {
-            return Err(anyhow!("Beep Boop LP ID not found"));
+        Ok(multiplier)
+    }
+
+    /// Get the weight of a trait key, None when it was never set or was cleared.
+    /// A stored 0 is a real weight that zeroes the rewards
+    fn trait_weight<P: KeyValuePointer>(
+        &self,
+        trait_weights: &P,
+        trait_key: &[u8],
+    ) -> Option<u128> {
+        let weight_pointer = trait_weights.select(&trait_key.to_vec());
+        if weight_pointer.get().is_empty() {
+            return None;
         }
 
-        let bytes = data.as_ref();
+        Some(weight_pointer.get_value::<u128>())
+    }
 
-        let alkane_id = AlkaneId {
-            block: u128::from_le_bytes(bytes[0..16].try_into().unwrap()),
-            tx: u128::from_le_bytes(bytes[16..32].try_into().unwrap()),
+    /// Get the NFT index of an orbital from the orbital itself (opcode 999)
+    fn orbital_index(&self, orbital_id: &AlkaneId) -> Result<u128> {
+        let cellpack = Cellpack {
+            target: *orbital_id,
+            inputs: vec![999],
         };
+        let index_data = self
+            .staticcall(&cellpack, &AlkaneTransferParcel::default(), self.fuel())?
+            .data;
 
-        Ok(alkane_id)
+        Ok(u128::from_le_bytes(
+            index_data
+                .try_into()
+                .map_err(|_| anyhow!("Invalid NFT index data"))?,
+        ))
     }
 
-    fn get_staked_by_lp(&self, block: u128, tx: u128) -> Result<CallResponse> {
-        let context = self.context()?;
-        let mut response = CallResponse::forward(&context.incoming_alkanes);
+    fn trait_value_string(&self, value: &Value) -> String {
+        match value {
+            Value::String(value) => value.clone(),
+            value => value.to_string(),
+        }
+    }
 
-        let pointer = self
-            .staked_id_pointer()
-            .select(&self.alkane_id_to_bytes(&AlkaneId { block, tx }));
-        let arc_bytes = pointer.get();
+    /// Unpack a trait key (`trait_type`, zero byte, `value`) from little-endian words
+    fn words_to_trait_key(&self, words: &[u128]) -> Result<Vec<u8>> {
+        let mut trait_key: Vec<u8> = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+        while trait_key.last() == Some(&0) {
+            trait_key.pop();
+        }
 
-        if arc_bytes.len() == 0 {
-            return Err(anyhow!("LP token has no staked orbital"));
+        let separator = trait_key
+            .iter()
+            .position(|byte| *byte == 0)
+            .ok_or_else(|| {
+                anyhow!("Trait must be a trait type and a value split by a zero byte")
+            })?;
+        if separator == 0 || std::str::from_utf8(&trait_key).is_err() {
+            return Err(anyhow!("Trait must be a UTF-8 trait type and value"));
         }
 
-        let alkane_id_string = format!(
-            "{}:{}",
-            u128::from_le_bytes(arc_bytes[0..16].try_into().unwrap()),
-            u128::from_le_bytes(arc_bytes[16..32].try_into().unwrap())
-        );
+        Ok(trait_key)
+    }
 
-        response.data = alkane_id_string.into_bytes();
+    fn get_forfeited_blocks(&self, block: u128, tx: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
 
+        let orbital_id = self.resolve_orbital_id(&AlkaneId { block, tx })?;
+        let forfeited_blocks = self
+            .forfeited_blocks_pointer()
+            .select(&self.alkane_id_to_bytes(&orbital_id))
+            .get_value::<u128>();
+
+        response.data = forfeited_blocks.to_le_bytes().to_vec();
         Ok(response)
     }
 
-    fn get_rewards(&self, block: u128, tx: u128) -> Result<CallResponse> {
+    fn get_unbonding(&self, block: u128, tx: u128) -> Result<CallResponse> {
         let context = self.context()?;
         let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-        let staked_alkane_id;
-
-        if !self.verify_id_collection(&AlkaneId { block, tx }) {
-            let pointer = self
-                .staked_id_pointer()
-                .select(&self.alkane_id_to_bytes(&AlkaneId { block, tx }));
-            let staked_id = pointer.get();
+        let orbital_id = self.resolve_orbital_id(&AlkaneId { block, tx })?;
+        let request_height = self
+            .unbonding_height_pointer()
+            .select(&self.alkane_id_to_bytes(&orbital_id))
+            .get_value::<u128>();
+        let ready_height = self.unbonding_ready_height(
+            request_height,
+            self.unbonding_blocks_pointer().get_value::<u128>(),
+        );
 
-            if staked_id.len() == 0 {
-                return Err(anyhow!("LP token has no staked orbital"));
-            }
-            let staked_id_bytes = staked_id.as_ref();
+        let mut data = Vec::with_capacity(32);
+        data.extend_from_slice(&request_height.to_le_bytes());
+        data.extend_from_slice(&ready_height.to_le_bytes());
 
-            staked_alkane_id = AlkaneId {
-                block: u128::from_le_bytes(staked_id_bytes[0..16].try_into().unwrap()),
-                tx: u128::from_le_bytes(staked_id_bytes[16..32].try_into().unwrap()),
-            };
-        } else {
-            staked_alkane_id = AlkaneId { block, tx };
-        }
+        response.data = data;
+        Ok(response)
+    }
 
-        let alkane_id_bytes = self.alkane_id_to_bytes(&staked_alkane_id);
+    fn get_stake_unlock_height(&self, block: u128, tx: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-        let total_staked_blocks = self
-            .total_staked_blocks_pointer()
-            .select(&alkane_id_bytes)
+        let orbital_id = self.resolve_orbital_id(&AlkaneId { block, tx })?;
+        let unlock_height = self
+            .unlock_height_pointer()
+            .select(&self.alkane_id_to_bytes(&orbital_id))
             .get_value::<u128>();
 
-        let staked_at_block = self
-            .stake_height_pointer()
-            .select(&alkane_id_bytes)
-            .get_value::<u128>();
+        response.data = unlock_height.to_le_bytes().to_vec();
+        Ok(response)
+    }
 
-        let mut rewards = total_staked_blocks;
+    /// Get the overlay tier progress of an orbital or LP
+    fn get_overlay_tier(&self, block: u128, tx: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-        if staked_at_block != 0 {
-            let period_blocks = u128::from(self.height()).saturating_sub(staked_at_block);
-            rewards = rewards
-                .checked_add(period_blocks)
-                .ok_or_else(|| anyhow!("Total staked blocks overflow"))?;
-        }
+        let orbital_id = self.resolve_orbital_id(&AlkaneId { block, tx })?;
+        let accrued_blocks = self.accrued_staked_blocks(&orbital_id)?;
+        let tier = self.overlay_tier(accrued_blocks);
+        let next_threshold = OVERLAY_TIERS
+            .get(tier + 1)
+            .map(|(threshold, _, _)| *threshold)
+            .unwrap_or(0);
 
-        response.data = rewards.to_le_bytes().to_vec();
+        let mut data = Vec::with_capacity(48);
+        data.extend_from_slice(&(tier as u128).to_le_bytes());
+        data.extend_from_slice(&accrued_blocks.to_le_bytes());
+        data.extend_from_slice(&next_threshold.to_le_bytes());
 
+        response.data = data;
         Ok(response)
     }
 
+    /// Get the orbital behind an id that is either an orbital or one of our LPs
+    fn resolve_orbital_id(&self, alkane_id: &AlkaneId) -> Result<AlkaneId> {
+        self.orbital_records().resolve(alkane_id)
+    }
+
+    /// Total staked blocks of an orbital, including its current stake period
+    fn accrued_staked_blocks(&self, orbital_id: &AlkaneId) -> Result<u128> {
+        self.orbital_records()
+            .accrued_blocks(orbital_id, u128::from(self.height()))
+    }
+
     /// Check if an orbital is eligible to be staked.
+    /// Orbitals not verified before need an eligibility proof after `block, tx`.
     pub fn get_stake_eligibility(&self, block: u128, tx: u128) -> Result<CallResponse> {
         let context = self.context()?;
         let mut response = CallResponse::forward(&context.incoming_alkanes);
 
+        // inputs: opcode, block, tx, then eligibility proofs
+        let proofs = EligibilityProof::parse_all(context.inputs.get(3..).unwrap_or_default())?;
+
         let alkane_id = AlkaneId { block, tx };
-        let eligible = self.verify_id_collection(&alkane_id)
+        let eligible = self.prove_eligibility(&alkane_id, &proofs).is_some()
             && self
                 .stake_height_pointer()
                 .select(&self.alkane_id_to_bytes(&alkane_id))
@@ -495,9 +2272,6 @@ impl Staking {
 
         let alkane_id = AlkaneId { block, tx };
 
-        if !self.verify_id_collection(&alkane_id) {
-            return Err(anyhow!("Orbital ID not from {}", CONTRACT_NAME));
-        }
         let alkane_id_bytes = self.alkane_id_to_bytes(&alkane_id);
         let staked_at_block = self
             .stake_height_pointer()
@@ -532,6 +2306,119 @@ impl Staking {
         Ok(response)
     }
 
+    pub fn get_stake_sessions(
+        &self,
+        block: u128,
+        tx: u128,
+        offset: u128,
+        limit: u128,
+    ) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let alkane_id = AlkaneId { block, tx };
+        let sessions_pointer = self
+            .stake_sessions_pointer()
+            .select(&self.alkane_id_to_bytes(&alkane_id));
+        let count = sessions_pointer.get_value::<u128>();
+
+        let mut data = count.to_le_bytes().to_vec();
+        for i in self.page_range(offset, limit, count) {
+            let session = sessions_pointer.select(&i.to_le_bytes().to_vec()).get();
+            data.extend_from_slice(session.as_ref());
+        }
+
+        response.data = data;
+        Ok(response)
+    }
+
+    pub fn get_staked_orbitals_count(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let length = self.staked_orbitals().len();
+        response.data = length.to_le_bytes().to_vec();
+
+        Ok(response)
+    }
+
+    pub fn get_staked_orbitals(&self, offset: u128, limit: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let staked_orbitals = self.staked_orbitals();
+
+        let mut data = Vec::new();
+        for i in self.page_range(offset, limit, staked_orbitals.len()) {
+            let orbital_id_bytes = staked_orbitals.get(i);
+            // Backfilled orbitals have no stake session, so the LP comes from
+            // the orbital to LP mapping every staked orbital has
+            let lp_id = self
+                .bytes_to_alkane_id(
+                    &self
+                        .lp_by_orbital_pointer()
+                        .select(&orbital_id_bytes.to_vec())
+                        .get(),
+                )
+                .unwrap_or(AlkaneId { block: 0, tx: 0 });
+            let staked_at_block = self
+                .stake_height_pointer()
+                .select(&orbital_id_bytes.to_vec())
+                .get_value::<u128>();
+
+            data.extend_from_slice(&orbital_id_bytes);
+            data.extend_from_slice(&self.alkane_id_to_bytes(&lp_id));
+            data.extend_from_slice(&staked_at_block.to_le_bytes());
+        }
+
+        response.data = data;
+        Ok(response)
+    }
+
+    pub fn get_vault_stats(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let current_staked = self.staked_orbitals().len();
+        let lifetime_staked = self.total_staked_pointer().get_value::<u128>();
+        let lifetime_unstaked = self.total_unstaked_pointer().get_value::<u128>();
+
+        let open_blocks = self.open_staked_blocks(
+            current_staked,
+            u128::from(self.height()),
+            self.staked_heights_sum_pointer().get_value::<u128>(),
+        )?;
+        let accrued_blocks = self
+            .total_accrued_blocks_pointer()
+            .get_value::<u128>()
+            .checked_add(open_blocks)
+            .ok_or_else(|| anyhow!("Total accrued blocks overflow"))?;
+
+        let mut data = Vec::with_capacity(80);
+        data.extend_from_slice(&current_staked.to_le_bytes());
+        data.extend_from_slice(&lifetime_staked.to_le_bytes());
+        data.extend_from_slice(&lifetime_unstaked.to_le_bytes());
+        data.extend_from_slice(&self.instances_count().to_le_bytes());
+        data.extend_from_slice(&accrued_blocks.to_le_bytes());
+
+        response.data = data;
+        Ok(response)
+    }
+
+    /// Blocks accrued by open sessions are the distance from each staked height
+    /// to the current one, which is derived from the sum of staked heights
+    fn open_staked_blocks(
+        &self,
+        current_staked: u128,
+        height: u128,
+        staked_heights_sum: u128,
+    ) -> Result<u128> {
+        Ok(current_staked
+            .checked_mul(height)
+            .ok_or_else(|| anyhow!("Open staked blocks overflow"))?
+            .saturating_sub(staked_heights_sum))
+    }
+
     pub fn get_total_supply(&self) -> Result<CallResponse> {
         let context = self.context()?;
         let mut response = CallResponse::forward(&context.incoming_alkanes);
@@ -566,65 +2453,157 @@ impl Staking {
         MAX_MINTS
     }
 
-    /// Select overlay image based on index (deterministic but appears random)
-    fn select_overlay_bytes(&self, index: u128) -> &'static [u8] {
-        match index % 4 {
-            0 => OVERLAY_BLUE_BYTES,
-            1 => OVERLAY_GLITCH_BYTES,
-            2 => OVERLAY_GREEN_BYTES,
-            3 => OVERLAY_PINK_BYTES,
-            _ => unreachable!(), // This should never happen with modulo 4
+    /// Get the overlay tier reached with the given accrued staked blocks
+    fn overlay_tier(&self, accrued_blocks: u128) -> usize {
+        OVERLAY_TIERS
+            .iter()
+            .rposition(|(threshold, _, _)| accrued_blocks >= *threshold)
+            .unwrap_or(0)
+    }
+
+    /// Get the overlay tier of the orbital behind the LP, tier 0 if none
+    fn index_overlay_tier(&self, index: u128) -> Result<usize> {
+        match self.resolve_lp_orbital(index) {
+            Some((_, orbital_id)) => {
+                Ok(self.overlay_tier(self.accrued_staked_blocks(&orbital_id)?))
+            }
+            None => Ok(0),
         }
     }
 
-    fn get_data(&self, index: u128) -> Result<CallResponse> {
-        let ctx = self.context()?;
-        let mut response = CallResponse::forward(&ctx.incoming_alkanes);
+    fn overlay_weights(&self) -> [u128; 4] {
+        let weights_pointer = self.overlay_weights_pointer();
+        let mut weights = [0u128; 4];
+        for (overlay, weight) in weights.iter_mut().enumerate() {
+            *weight = weights_pointer
+                .select(&(overlay as u128).to_le_bytes().to_vec())
+                .get_value::<u128>();
+        }
+        weights
+    }
 
-        let collection_id = AlkaneId {
-            block: BEEP_BOOP_BLOCK,
-            tx: BEEP_BOOP_COLLECTION_ID,
-        };
+    /// Get the overlay shown for an index: drawn by rarity weights when any
+    /// are set, the staking tier otherwise
+    fn index_overlay(&self, index: u128) -> Result<usize> {
+        match self.roll_overlay(&self.overlay_weights(), index)? {
+            Some(overlay) => Ok(overlay),
+            None => self.index_overlay_tier(index),
+        }
+    }
+
+    /// Draw the overlay of an index from the rarity weights, None when every
+    /// weight is zero
+    fn roll_overlay(&self, weights: &[u128; 4], index: u128) -> Result<Option<usize>> {
+        let total_weight = weights
+            .iter()
+            .try_fold(0u128, |total, weight| total.checked_add(*weight))
+            .ok_or_else(|| anyhow!("Overlay weights overflow"))?;
+        if total_weight == 0 {
+            return Ok(None);
+        }
+
+        let hash = Sha256::hash(&index.to_le_bytes());
+        let mut roll = u128::from_le_bytes(hash[0..16].try_into().unwrap()) % total_weight;
+        for (overlay, weight) in weights.iter().enumerate() {
+            if roll < *weight {
+                return Ok(Some(overlay));
+            }
+            roll -= weight;
+        }
+
+        Err(anyhow!("Overlay roll out of range"))
+    }
+
+    /// Fetch the upstream PNG of the orbital behind the LP
+    fn fetch_base_png(&self, index: u128) -> Result<Vec<u8>> {
+        let collection_id = self.upstream_collection_id(index)?;
 
         let cell = Cellpack {
             target: collection_id,
             inputs: vec![1000, index],
         };
-        let base_png = self
+        Ok(self
             .staticcall(&cell, &AlkaneTransferParcel::default(), self.fuel())?
-            .data;
+            .data)
+    }
+
+    fn get_data(&self, index: u128) -> Result<CallResponse> {
+        let ctx = self.context()?;
+        let mut response = CallResponse::forward(&ctx.incoming_alkanes);
 
-        // decode both images
-        let mut base: RgbaImage = image::load_from_memory(&base_png)?.to_rgba8();
-        let overlay: RgbaImage =
-            image::load_from_memory(self.select_overlay_bytes(index))?.to_rgba8();
+        let base_png = self.fetch_base_png(index)?;
 
-        // Get overlay dimensions
-        let (overlay_width, overlay_height) = overlay.dimensions();
+        let (_, _, overlay_bytes) = OVERLAY_TIERS[self.index_overlay(index)?];
 
-        // Center the overlay on the base image
-        let x_offset = (420 - overlay_width) / 2;
-        let y_offset = (420 - overlay_height) / 2;
+        response.data = render::composite_png(&base_png, overlay_bytes)?;
+        Ok(response)
+    }
 
-        imageops::overlay(&mut base, &overlay, x_offset as i64, y_offset as i64);
+    fn get_data_svg(&self, index: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-        // re-encode
-        let mut out = Vec::new();
-        DynamicImage::ImageRgba8(base).write_to(&mut Cursor::new(&mut out), ImageFormat::Png)?;
+        let base_png = self.fetch_base_png(index)?;
+        let (_, _, overlay_bytes) = OVERLAY_TIERS[self.index_overlay(index)?];
 
-        response.data = out;
+        response.data = render::svg_document(&base_png, overlay_bytes)?.into_bytes();
         Ok(response)
     }
 
-    fn get_attributes(&self, index: u128) -> Result<CallResponse> {
+    fn get_render(&self, index: u128, variant: u128, edge: u128) -> Result<CallResponse> {
         let context = self.context()?;
         let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-        let collection_id = AlkaneId {
-            block: BEEP_BOOP_BLOCK,
-            tx: BEEP_BOOP_COLLECTION_ID,
+        let variant = RenderVariant::from_selector(variant)?;
+        let base_png = self.fetch_base_png(index)?;
+        if variant == RenderVariant::Raw {
+            response.data = base_png;
+            return Ok(response);
+        }
+
+        let (_, _, overlay_bytes) = OVERLAY_TIERS[self.index_overlay(index)?];
+        response.data = match variant {
+            RenderVariant::Thumbnail => {
+                let edge = u32::try_from(edge).map_err(|_| anyhow!("Thumbnail edge too large"))?;
+                render::thumbnail_png(&render::composite_png(&base_png, overlay_bytes)?, edge)?
+            }
+            RenderVariant::Svg => render::svg_document(&base_png, overlay_bytes)?.into_bytes(),
+            _ => render::composite_png(&base_png, overlay_bytes)?,
+        };
+        Ok(response)
+    }
+
+    fn get_render_content_type(&self, index: u128, variant: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let variant = RenderVariant::from_selector(variant)?;
+        // Only the raw passthrough depends on what the upstream serves
+        let rendered = if variant == RenderVariant::Raw {
+            self.fetch_base_png(index)?
+        } else {
+            Vec::new()
         };
 
+        response.data = variant.content_type(&rendered).as_bytes().to_vec();
+        Ok(response)
+    }
+
+    fn get_svg_content_type(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = String::from("image/svg+xml").into_bytes();
+        Ok(response)
+    }
+
+    /// Get the upstream attributes merged with the staking state of the orbital
+    fn get_attributes(&self, index: u128) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        let collection_id = self.upstream_collection_id(index)?;
+
         let cellpack = Cellpack {
             target: collection_id,
             inputs: vec![1002, index],
@@ -633,11 +2612,105 @@ impl Staking {
         let call_response =
             self.staticcall(&cellpack, &AlkaneTransferParcel::default(), self.fuel())?;
 
-        response.data = call_response.data;
+        let staking_attributes = self.staking_attributes(index)?;
+        response.data = self.merge_attributes(&call_response.data, staking_attributes);
 
         Ok(response)
     }
 
+    /// Name the overlay that is drawn. The staking tier is only reported while
+    /// it drives the overlay, so a rarity draw is never shown as a tier
+    fn overlay_attributes(
+        &self,
+        overlay: usize,
+        staking_tier: Option<usize>,
+    ) -> Map<String, Value> {
+        let (_, overlay_name, _) = OVERLAY_TIERS[overlay];
+
+        let mut attributes = Map::new();
+        attributes.insert("Overlay".to_string(), Value::from(overlay_name));
+        if let Some(tier) = staking_tier {
+            attributes.insert("Overlay Tier".to_string(), Value::from(tier as u64));
+        }
+        attributes
+    }
+
+    fn staking_attributes(&self, index: u128) -> Result<Map<String, Value>> {
+        let mut attributes = match self.roll_overlay(&self.overlay_weights(), index)? {
+            Some(overlay) => self.overlay_attributes(overlay, None),
+            None => {
+                let tier = self.index_overlay_tier(index)?;
+                self.overlay_attributes(tier, Some(tier))
+            }
+        };
+
+        if let Some((lp_id, orbital_id)) = self.resolve_lp_orbital(index) {
+            let orbital_id_bytes = self.alkane_id_to_bytes(&orbital_id);
+
+            let staked_at_block = self
+                .stake_height_pointer()
+                .select(&orbital_id_bytes)
+                .get_value::<u128>();
+            let total_staked_blocks = self
+                .total_staked_blocks_pointer()
+                .select(&orbital_id_bytes)
+                .get_value::<u128>();
+
+            attributes.insert(
+                "LP".to_string(),
+                Value::from(format!("{}:{}", lp_id.block, lp_id.tx)),
+            );
+            attributes.insert(
+                "Orbital".to_string(),
+                Value::from(format!("{}:{}", orbital_id.block, orbital_id.tx)),
+            );
+            attributes.insert("Staked".to_string(), Value::from(staked_at_block != 0));
+            attributes.insert(
+                "Staked Height".to_string(),
+                self.json_number(staked_at_block),
+            );
+            attributes.insert(
+                "Total Staked Blocks".to_string(),
+                self.json_number(total_staked_blocks),
+            );
+            attributes.insert(
+                "Accrued Rewards".to_string(),
+                self.json_number(self.accrued_staked_blocks(&orbital_id)?),
+            );
+        }
+
+        Ok(attributes)
+    }
+
+    /// Merge staking attributes into the upstream JSON, either as keys of an
+    /// object or as `trait_type`/`value` entries of a list. Malformed upstream
+    /// JSON is replaced by the staking attributes alone.
+    fn merge_attributes(&self, upstream: &[u8], staking_attributes: Map<String, Value>) -> Vec<u8> {
+        let merged = match serde_json::from_slice::<Value>(upstream) {
+            Ok(Value::Object(mut attributes)) => {
+                attributes.extend(staking_attributes);
+                Value::Object(attributes)
+            }
+            Ok(Value::Array(mut attributes)) => {
+                for (trait_type, value) in staking_attributes {
+                    attributes.push(json!({ "trait_type": trait_type, "value": value }));
+                }
+                Value::Array(attributes)
+            }
+            _ => Value::Object(staking_attributes),
+        };
+
+        merged.to_string().into_bytes()
+    }
+
+    /// JSON numbers can't hold every u128, larger values are written as strings
+    fn json_number(&self, value: u128) -> Value {
+        match u64::try_from(value) {
+            Ok(value) => Value::from(value),
+            Err(_) => Value::from(value.to_string()),
+        }
+    }
+
     /// Get the collection identifier
     /// Returns the collection identifier in the format "block:tx"
     fn get_collection_identifier(&self) -> Result<CallResponse> {
@@ -651,6 +2724,16 @@ impl Staking {
         Ok(response)
     }
 
+    /// Get the collection identifier as 32 bytes, the layout of `alkane_id_to_bytes`
+    fn get_collection_identifier_binary(&self) -> Result<CallResponse> {
+        let context = self.context()?;
+        let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+        response.data = self.alkane_id_to_bytes(&context.myself);
+
+        Ok(response)
+    }
+
     pub fn alkane_id_to_bytes(&self, alkane_id: &AlkaneId) -> Vec<u8> {
         let mut bytes = Vec::with_capacity(32);
         bytes.extend_from_slice(&alkane_id.block.to_le_bytes());
@@ -658,27 +2741,71 @@ impl Staking {
         bytes
     }
 
-    pub fn verify_id_collection(&self, orbital_id: &AlkaneId) -> bool {
-        orbital_id.block == BEEP_BOOP_BLOCK && BEEP_BOOP_IDS.contains(&orbital_id.tx)
+    fn bytes_to_alkane_id(&self, bytes: &[u8]) -> Result<AlkaneId> {
+        if bytes.len() != 32 {
+            return Err(anyhow!("Invalid alkane ID data length"));
+        }
+
+        Ok(AlkaneId {
+            block: u128::from_le_bytes(bytes[0..16].try_into().unwrap()),
+            tx: u128::from_le_bytes(bytes[16..32].try_into().unwrap()),
+        })
     }
 
-    fn add_instance(&self, instance_id: &AlkaneId, index: &u128) -> Result<u128> {
+    fn add_instance(
+        &self,
+        instance_id: &AlkaneId,
+        collection_id: &AlkaneId,
+        index: u128,
+    ) -> Result<u128> {
         let count = self.instances_count();
         let new_count = count.checked_add(1).ok_or_else(|| anyhow!("Minted out"))?;
 
-        let mut bytes = Vec::with_capacity(32);
-        bytes.extend_from_slice(&instance_id.block.to_le_bytes());
-        bytes.extend_from_slice(&instance_id.tx.to_le_bytes());
-
-        let bytes_vec = index.to_le_bytes().to_vec();
-        let mut instance_pointer = self.instances_pointer().select(&bytes_vec);
-        instance_pointer.set(Arc::new(bytes));
+        let mut instance_pointer = self
+            .instances_pointer()
+            .select(&self.instance_key(collection_id, index));
+        instance_pointer.set(Arc::new(self.alkane_id_to_bytes(instance_id)));
 
         self.set_instances_count(new_count);
 
         Ok(new_count)
     }
 
+    /// Key an LP instance by its orbital's collection and index, since indexes
+    /// repeat across collections
+    fn instance_key(&self, collection_id: &AlkaneId, index: u128) -> Vec<u8> {
+        let mut key = self.alkane_id_to_bytes(collection_id);
+        key.extend_from_slice(&index.to_le_bytes());
+        key
+    }
+
+    fn instance_lp_id(&self, collection_id: &AlkaneId, index: u128) -> Option<AlkaneId> {
+        self.find_instance(&self.instances_pointer(), collection_id, index)
+    }
+
+    /// Find the LP minted for an orbital, falling back to the index-only key
+    /// Beep Boop LPs were stored under before other collections were eligible
+    fn find_instance<P: KeyValuePointer>(
+        &self,
+        instances: &P,
+        collection_id: &AlkaneId,
+        index: u128,
+    ) -> Option<AlkaneId> {
+        let instance = instances
+            .select(&self.instance_key(collection_id, index))
+            .get();
+        if let Ok(lp_id) = self.bytes_to_alkane_id(&instance) {
+            return Some(lp_id);
+        }
+
+        if *collection_id != BEEP_BOOP_COLLECTION {
+            return None;
+        }
+
+        let legacy_instance = instances.select(&index.to_le_bytes().to_vec()).get();
+        self.bytes_to_alkane_id(&legacy_instance).ok()
+    }
+
     fn instances_count(&self) -> u128 {
         self.instances_pointer().get_value::<u128>()
     }
@@ -691,6 +2818,17 @@ impl Staking {
         StoragePointer::from_keyword("/instances")
     }
 
+    /// Get the staking records of every orbital
+    fn orbital_records(&self) -> OrbitalRecords<StoragePointer> {
+        OrbitalRecords::new(
+            self.stake_height_pointer(),
+            self.total_staked_blocks_pointer(),
+            self.staked_id_pointer(),
+            self.lp_by_orbital_pointer(),
+            self.eligible_orbitals_pointer(),
+        )
+    }
+
     /// Get storage pointer for stake height records
     pub fn stake_height_pointer(&self) -> StoragePointer {
         StoragePointer::from_keyword("/stake-height")
@@ -701,10 +2839,134 @@ impl Staking {
         StoragePointer::from_keyword("/address-staked-pointer")
     }
 
+    /// Get storage pointer for orbital-to-LP mapping
+    pub fn lp_by_orbital_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/lp-by-orbital")
+    }
+
     pub fn total_staked_blocks_pointer(&self) -> StoragePointer {
         StoragePointer::from_keyword("/total-staked-blocks")
     }
 
+    /// Get storage pointer for the per-orbital stake session history
+    pub fn stake_sessions_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/stake-sessions")
+    }
+
+    /// Get the indexed set of currently staked orbitals
+    fn staked_orbitals(&self) -> IdSet<StoragePointer> {
+        IdSet::new(
+            self.staked_orbitals_pointer(),
+            self.staked_orbital_position_pointer(),
+        )
+    }
+
+    /// Get storage pointer for the indexed set of currently staked orbitals
+    pub fn staked_orbitals_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/staked-orbitals")
+    }
+
+    /// Get storage pointer for the position of an orbital in the staked set
+    pub fn staked_orbital_position_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/staked-orbital-position")
+    }
+
+    /// Get storage pointer for the sum of stake heights of all open sessions
+    pub fn staked_heights_sum_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/staked-heights-sum")
+    }
+
+    /// Get storage pointer for the staked blocks accrued by all closed sessions
+    pub fn total_accrued_blocks_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/total-accrued-blocks")
+    }
+
+    /// Get storage pointer for the stake pause flag
+    pub fn stake_paused_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/stake-paused")
+    }
+
+    /// Get storage pointer for the unstake pause flag
+    pub fn unstake_paused_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/unstake-paused")
+    }
+
+    /// Get storage pointer for the list of eligible collections
+    pub fn eligible_collections_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/eligible-collections")
+    }
+
+    /// Get storage pointer for the registered flag of each collection
+    pub fn collection_registered_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/collection-registered")
+    }
+
+    /// Get storage pointer for verified orbital to collection mapping
+    pub fn eligible_orbitals_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/eligible-orbitals")
+    }
+
+    /// Get storage pointer for the global minimum lock in blocks
+    pub fn lock_blocks_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/lock-blocks")
+    }
+
+    /// Get storage pointer for the early-unstake penalty in basis points
+    pub fn penalty_bps_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/penalty-bps")
+    }
+
+    /// Get storage pointer for the height the current stake of each orbital unlocks at
+    pub fn unlock_height_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/unlock-height")
+    }
+
+    /// Get storage pointer for the staked blocks forfeited by each orbital
+    pub fn forfeited_blocks_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/forfeited-blocks")
+    }
+
+    /// Get storage pointer for the staked blocks forfeited across the vault
+    pub fn total_forfeited_blocks_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/total-forfeited-blocks")
+    }
+
+    /// Get storage pointer for the unbonding period in blocks
+    pub fn unbonding_blocks_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/unbonding-blocks")
+    }
+
+    /// Get storage pointer for the height unbonding was requested at, per orbital
+    pub fn unbonding_height_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/unbonding-height")
+    }
+
+    /// Get storage pointer for the unlock height and credited blocks held with
+    /// each unbonding request
+    pub fn unbonding_request_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/unbonding-request")
+    }
+
+    /// Get storage pointer for the claim contract GetPosition reads rewards from
+    pub fn claim_contract_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/claim-contract")
+    }
+
+    /// Get storage pointer for the rarity weight of each overlay
+    pub fn overlay_weights_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/overlay-weights")
+    }
+
+    /// Get storage pointer for the reward weight of each trait key
+    pub fn trait_weights_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/trait-weights")
+    }
+
+    /// Get storage pointer for the eligible Merkle root and leaf count of each collection
+    pub fn collection_root_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/collection-root")
+    }
+
     pub fn total_staked_pointer(&self) -> StoragePointer {
         StoragePointer::from_keyword("/total-staked")
     }
@@ -712,6 +2974,11 @@ impl Staking {
     pub fn total_unstaked_pointer(&self) -> StoragePointer {
         StoragePointer::from_keyword("/total-unstaked")
     }
+
+    /// Get storage pointer for the event log, the count lives on the base pointer
+    pub fn events_pointer(&self) -> StoragePointer {
+        StoragePointer::from_keyword("/events")
+    }
 }
 
 declare_alkane! {
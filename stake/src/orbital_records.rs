@@ -0,0 +1,202 @@
+use crate::BEEP_BOOP_COLLECTION;
+use alkanes_support::id::AlkaneId;
+use anyhow::{anyhow, Result};
+use metashrew_support::index_pointer::KeyValuePointer;
+
+/// Size of an encoded position: orbital id, LP id, index, staked flag, then
+/// staked height, total staked blocks, current accrual and pending rewards
+pub const POSITION_SIZE: usize = 32 + 32 + 16 + 1 + 16 * 4;
+
+/// Everything GetPosition reports about one orbital
+#[derive(Debug, PartialEq)]
+pub struct Position {
+    pub orbital_id: AlkaneId,
+    /// The LP bound to the orbital, None if none was ever minted
+    pub lp_id: Option<AlkaneId>,
+    pub index: u128,
+    /// Height of the open session, 0 when not staked
+    pub staked_at_block: u128,
+    pub total_staked_blocks: u128,
+    /// Blocks of the open session
+    pub current_accrual: u128,
+    /// $BB the claim contract would pay out now
+    pub pending_rewards: u128,
+}
+
+impl Position {
+    /// Encode the position as POSITION_SIZE little-endian bytes, the LP id
+    /// zeroed when there is none
+    pub fn encode(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(POSITION_SIZE);
+        data.extend_from_slice(&id_bytes(&self.orbital_id));
+        match &self.lp_id {
+            Some(lp_id) => data.extend_from_slice(&id_bytes(lp_id)),
+            None => data.extend_from_slice(&[0u8; 32]),
+        }
+        data.extend_from_slice(&self.index.to_le_bytes());
+        data.push((self.staked_at_block != 0) as u8);
+        data.extend_from_slice(&self.staked_at_block.to_le_bytes());
+        data.extend_from_slice(&self.total_staked_blocks.to_le_bytes());
+        data.extend_from_slice(&self.current_accrual.to_le_bytes());
+        data.extend_from_slice(&self.pending_rewards.to_le_bytes());
+        data
+    }
+}
+
+/// Per-orbital staking records, keyed by the 32-byte orbital id.
+///
+/// Reads only depend on records every vault layout has written, so orbitals
+/// staked before the eligibility cache existed resolve like any other.
+pub struct OrbitalRecords<P: KeyValuePointer> {
+    stake_heights: P,
+    total_staked_blocks: P,
+    staked_ids: P,
+    lps_by_orbital: P,
+    eligible_orbitals: P,
+}
+
+impl<P: KeyValuePointer> OrbitalRecords<P> {
+    pub fn new(
+        stake_heights: P,
+        total_staked_blocks: P,
+        staked_ids: P,
+        lps_by_orbital: P,
+        eligible_orbitals: P,
+    ) -> Self {
+        Self {
+            stake_heights,
+            total_staked_blocks,
+            staked_ids,
+            lps_by_orbital,
+            eligible_orbitals,
+        }
+    }
+
+    /// Get the orbital behind an id: the orbital staked for it when it is one
+    /// of our LPs, else the id itself
+    pub fn resolve(&self, alkane_id: &AlkaneId) -> Result<AlkaneId> {
+        let staked_id = self.staked_ids.select(&id_bytes(alkane_id)).get();
+
+        if staked_id.is_empty() {
+            return Ok(*alkane_id);
+        }
+
+        read_id(&staked_id)
+    }
+
+    /// Get the collection of an orbital. Orbitals staked before the cache
+    /// existed have staking history but no entry; that vault only staked Beep
+    /// Boop, so they belong to it
+    pub fn collection_id(&self, orbital_id: &AlkaneId) -> Result<AlkaneId> {
+        let collection_id = self.eligible_orbitals.select(&id_bytes(orbital_id)).get();
+
+        if !collection_id.is_empty() {
+            return read_id(&collection_id);
+        }
+
+        if self.stake_height(orbital_id) != 0 || self.total_staked_blocks(orbital_id) != 0 {
+            return Ok(BEEP_BOOP_COLLECTION);
+        }
+
+        Err(anyhow!(
+            "Orbital {}:{} is not eligible",
+            orbital_id.block,
+            orbital_id.tx
+        ))
+    }
+
+    /// Get the LP bound to an orbital, None if none was ever minted
+    pub fn lp_id(&self, orbital_id: &AlkaneId) -> Option<AlkaneId> {
+        read_id(&self.lps_by_orbital.select(&id_bytes(orbital_id)).get()).ok()
+    }
+
+    /// Get the position of an orbital at `height`; an orbital that was never
+    /// staked reads as zeros
+    pub fn position(
+        &self,
+        orbital_id: &AlkaneId,
+        index: u128,
+        height: u128,
+        pending_rewards: u128,
+    ) -> Position {
+        let staked_at_block = self.stake_height(orbital_id);
+        let current_accrual = if staked_at_block == 0 {
+            0
+        } else {
+            height.saturating_sub(staked_at_block)
+        };
+
+        Position {
+            orbital_id: *orbital_id,
+            lp_id: self.lp_id(orbital_id),
+            index,
+            staked_at_block,
+            total_staked_blocks: self.total_staked_blocks(orbital_id),
+            current_accrual,
+            pending_rewards,
+        }
+    }
+
+    /// Get the height an orbital was staked at, 0 when it is not staked
+    pub fn stake_height(&self, orbital_id: &AlkaneId) -> u128 {
+        self.stake_heights
+            .select(&id_bytes(orbital_id))
+            .get_value::<u128>()
+    }
+
+    /// Get the blocks an orbital was staked for in its closed sessions
+    pub fn total_staked_blocks(&self, orbital_id: &AlkaneId) -> u128 {
+        self.total_staked_blocks
+            .select(&id_bytes(orbital_id))
+            .get_value::<u128>()
+    }
+
+    /// Total staked blocks of an orbital at `height`, including its open session
+    pub fn accrued_blocks(&self, orbital_id: &AlkaneId, height: u128) -> Result<u128> {
+        let staked_at_block = self.stake_height(orbital_id);
+        let mut accrued = self.total_staked_blocks(orbital_id);
+
+        if staked_at_block != 0 {
+            accrued = accrued
+                .checked_add(height.saturating_sub(staked_at_block))
+                .ok_or_else(|| anyhow!("Total staked blocks overflow"))?;
+        }
+
+        Ok(accrued)
+    }
+}
+
+#[cfg(test)]
+impl OrbitalRecords<orbitals_common::memory_pointer::MemoryPointer> {
+    /// Orbital records held in memory under `prefix`, so tests don't share them
+    pub fn in_memory(prefix: &str) -> Self {
+        use orbitals_common::memory_pointer::MemoryPointer;
+
+        let pointer = |name: &str| MemoryPointer::from_keyword(&format!("{}/{}", prefix, name));
+        Self::new(
+            pointer("stake-height"),
+            pointer("total-staked-blocks"),
+            pointer("address-staked-pointer"),
+            pointer("lp-by-orbital"),
+            pointer("eligible-orbitals"),
+        )
+    }
+}
+
+fn id_bytes(alkane_id: &AlkaneId) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(32);
+    bytes.extend_from_slice(&alkane_id.block.to_le_bytes());
+    bytes.extend_from_slice(&alkane_id.tx.to_le_bytes());
+    bytes
+}
+
+fn read_id(bytes: &[u8]) -> Result<AlkaneId> {
+    if bytes.len() != 32 {
+        return Err(anyhow!("Invalid alkane ID data length"));
+    }
+
+    Ok(AlkaneId {
+        block: u128::from_le_bytes(bytes[0..16].try_into().unwrap()),
+        tx: u128::from_le_bytes(bytes[16..32].try_into().unwrap()),
+    })
+}
@@ -0,0 +1,399 @@
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use image::io::Reader as ImageReader;
+use image::{imageops, ColorType, DynamicImage, ImageFormat, RgbaImage};
+use std::io::Cursor;
+
+/// Largest edge, in pixels, accepted for the base or the overlay
+pub const MAX_RENDER_EDGE: u32 = 2048;
+
+/// Smallest and largest thumbnail edge a caller can ask for
+pub const MIN_THUMBNAIL_EDGE: u32 = 16;
+pub const MAX_THUMBNAIL_EDGE: u32 = 512;
+
+/// The image variants the render opcode can produce
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RenderVariant {
+    /// The untouched upstream image, in whatever format the collection serves
+    Raw,
+    /// The upstream PNG with the staking overlay
+    Composite,
+    /// The composite downscaled to a caller-chosen edge
+    Thumbnail,
+    /// The composite as an SVG document, see `svg_document`
+    Svg,
+}
+
+impl RenderVariant {
+    pub fn from_selector(selector: u128) -> Result<Self> {
+        match selector {
+            0 => Ok(RenderVariant::Raw),
+            1 => Ok(RenderVariant::Composite),
+            2 => Ok(RenderVariant::Thumbnail),
+            3 => Ok(RenderVariant::Svg),
+            _ => Err(anyhow!("Unknown render variant {}", selector)),
+        }
+    }
+
+    /// Content type of `rendered`, the bytes this variant produced. Only the raw
+    /// passthrough depends on them
+    pub fn content_type(&self, rendered: &[u8]) -> &'static str {
+        match self {
+            RenderVariant::Raw => sniff_content_type(rendered),
+            RenderVariant::Svg => "image/svg+xml",
+            _ => "image/png",
+        }
+    }
+}
+
+/// Detect the content type of an image from its magic bytes
+pub fn sniff_content_type(bytes: &[u8]) -> &'static str {
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        return "image/png";
+    }
+    if bytes.starts_with(&[0xff, 0xd8, 0xff]) {
+        return "image/jpeg";
+    }
+    if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        return "image/gif";
+    }
+    if bytes.len() >= 12 && bytes.starts_with(b"RIFF") && &bytes[8..12] == b"WEBP" {
+        return "image/webp";
+    }
+
+    let text = bytes.trim_ascii_start();
+    if text.starts_with(b"<svg") || (text.starts_with(b"<?xml") && contains(text, b"<svg")) {
+        return "image/svg+xml";
+    }
+
+    "application/octet-stream"
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+/// Read the dimensions of an encoded image from its header, without decoding pixels
+pub fn image_dimensions(bytes: &[u8]) -> Result<(u32, u32)> {
+    Ok(ImageReader::new(Cursor::new(bytes))
+        .with_guessed_format()?
+        .into_dimensions()?)
+}
+
+/// Reject empty or oversized images before any pixel is decoded
+fn check_dimensions(label: &str, (width, height): (u32, u32)) -> Result<()> {
+    if width == 0 || height == 0 || width > MAX_RENDER_EDGE || height > MAX_RENDER_EDGE {
+        return Err(anyhow!(
+            "Unsupported {} size {}x{}, edges must be 1 to {} pixels",
+            label,
+            width,
+            height,
+            MAX_RENDER_EDGE
+        ));
+    }
+    Ok(())
+}
+
+/// Decode an 8-bit image into RGBA, rejecting sizes and color types we don't render
+fn decode_rgba(label: &str, bytes: &[u8]) -> Result<RgbaImage> {
+    check_dimensions(label, image_dimensions(bytes)?)?;
+
+    let image = image::load_from_memory(bytes)?;
+    match image.color() {
+        ColorType::L8 | ColorType::La8 | ColorType::Rgb8 | ColorType::Rgba8 => Ok(image.to_rgba8()),
+        color => Err(anyhow!("Unsupported {} color type {:?}", label, color)),
+    }
+}
+
+/// Get the rectangle (x, y, width, height) the overlay is drawn in: downscaled to
+/// fit the base while keeping its aspect ratio when it is larger, then centered.
+/// An overlay that already fits keeps its native size
+pub fn overlay_placement(base: (u32, u32), overlay: (u32, u32)) -> (i64, i64, u32, u32) {
+    let (base_width, base_height) = (u64::from(base.0), u64::from(base.1));
+    let (overlay_width, overlay_height) = (u64::from(overlay.0), u64::from(overlay.1));
+
+    let (width, height) = if overlay_width <= base_width && overlay_height <= base_height {
+        (overlay_width, overlay_height)
+    } else if overlay_width * base_height <= base_width * overlay_height {
+        (overlay_width * base_height / overlay_height, base_height)
+    } else {
+        (base_width, overlay_height * base_width / overlay_width)
+    };
+    let (width, height) = (width.max(1), height.max(1));
+
+    (
+        ((base_width - width) / 2) as i64,
+        ((base_height - height) / 2) as i64,
+        width as u32,
+        height as u32,
+    )
+}
+
+/// Composite the overlay on the base image and re-encode the result as PNG
+pub fn composite_png(base_png: &[u8], overlay_png: &[u8]) -> Result<Vec<u8>> {
+    let mut base = decode_rgba("base image", base_png)?;
+    let mut overlay = decode_rgba("overlay", overlay_png)?;
+
+    let (x_offset, y_offset, width, height) =
+        overlay_placement(base.dimensions(), overlay.dimensions());
+    if overlay.dimensions() != (width, height) {
+        overlay = imageops::resize(&overlay, width, height, imageops::FilterType::Nearest);
+    }
+
+    imageops::overlay(&mut base, &overlay, x_offset, y_offset);
+
+    let mut out = Vec::new();
+    DynamicImage::ImageRgba8(base).write_to(&mut Cursor::new(&mut out), ImageFormat::Png)?;
+    Ok(out)
+}
+
+/// Downscale a PNG so its longest side is `edge` pixels, keeping the aspect ratio.
+/// Images already within `edge` are returned as they are.
+pub fn thumbnail_png(png: &[u8], edge: u32) -> Result<Vec<u8>> {
+    if !(MIN_THUMBNAIL_EDGE..=MAX_THUMBNAIL_EDGE).contains(&edge) {
+        return Err(anyhow!(
+            "Unsupported thumbnail edge {}, must be {} to {} pixels",
+            edge,
+            MIN_THUMBNAIL_EDGE,
+            MAX_THUMBNAIL_EDGE
+        ));
+    }
+
+    let image = decode_rgba("image", png)?;
+    let (width, height) = image.dimensions();
+    if width.max(height) <= edge {
+        return Ok(png.to_vec());
+    }
+
+    let (_, _, thumb_width, thumb_height) = overlay_placement((edge, edge), (width, height));
+    let thumbnail = imageops::resize(
+        &image,
+        thumb_width,
+        thumb_height,
+        imageops::FilterType::Triangle,
+    );
+
+    let mut out = Vec::new();
+    DynamicImage::ImageRgba8(thumbnail).write_to(&mut Cursor::new(&mut out), ImageFormat::Png)?;
+    Ok(out)
+}
+
+/// Build an SVG document that stacks the overlay, downscaled if larger and centered, on top of the base image.
+/// Both images are embedded as data URIs, so no pixel work is done.
+pub fn svg_document(base_png: &[u8], overlay_png: &[u8]) -> Result<String> {
+    let (base_width, base_height) = image_dimensions(base_png)?;
+    check_dimensions("base image", (base_width, base_height))?;
+    let overlay_dimensions = image_dimensions(overlay_png)?;
+    check_dimensions("overlay", overlay_dimensions)?;
+
+    let (x_offset, y_offset, overlay_width, overlay_height) =
+        overlay_placement((base_width, base_height), overlay_dimensions);
+
+    Ok(format!(
+        concat!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{bw}" height="{bh}" viewBox="0 0 {bw} {bh}" style="image-rendering:pixelated">"#,
+            r#"<image width="{bw}" height="{bh}" href="data:{base_type};base64,{base}"/>"#,
+            r#"<image x="{x}" y="{y}" width="{ow}" height="{oh}" href="data:image/png;base64,{overlay}"/>"#,
+            "</svg>"
+        ),
+        bw = base_width,
+        bh = base_height,
+        base_type = sniff_content_type(base_png),
+        base = STANDARD.encode(base_png),
+        x = x_offset,
+        y = y_offset,
+        ow = overlay_width,
+        oh = overlay_height,
+        overlay = STANDARD.encode(overlay_png),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OVERLAY_BLUE_BYTES;
+    use image::{ImageBuffer, Rgba};
+
+    fn solid_png(width: u32, height: u32) -> Vec<u8> {
+        let base = RgbaImage::from_pixel(width, height, Rgba([0, 0, 255, 255]));
+        let mut out = Vec::new();
+        DynamicImage::ImageRgba8(base)
+            .write_to(&mut Cursor::new(&mut out), ImageFormat::Png)
+            .unwrap();
+        out
+    }
+
+    #[test]
+    fn placement_matches_a_420_canvas() {
+        assert_eq!(overlay_placement((420, 420), (420, 420)), (0, 0, 420, 420));
+    }
+
+    #[test]
+    fn placement_downscales_and_centers_larger_overlays() {
+        assert_eq!(overlay_placement((210, 210), (420, 420)), (0, 0, 210, 210));
+        assert_eq!(
+            overlay_placement((600, 400), (420, 420)),
+            (100, 0, 400, 400)
+        );
+        assert_eq!(
+            overlay_placement((400, 600), (420, 420)),
+            (0, 100, 400, 400)
+        );
+        assert_eq!(overlay_placement((100, 100), (500, 420)), (0, 8, 100, 84));
+    }
+
+    #[test]
+    fn placement_centers_smaller_overlays_at_native_size() {
+        assert_eq!(
+            overlay_placement((420, 420), (300, 300)),
+            (60, 60, 300, 300)
+        );
+        assert_eq!(
+            overlay_placement((840, 840), (420, 420)),
+            (210, 210, 420, 420)
+        );
+        assert_eq!(
+            overlay_placement((600, 400), (300, 100)),
+            (150, 150, 300, 100)
+        );
+    }
+
+    #[test]
+    fn composite_keeps_non_420_base_dimensions() {
+        for (width, height) in [(64, 64), (300, 300), (600, 400), (1000, 1000)] {
+            let out = composite_png(&solid_png(width, height), OVERLAY_BLUE_BYTES).unwrap();
+            assert_eq!(image_dimensions(&out).unwrap(), (width, height));
+        }
+    }
+
+    #[test]
+    fn composite_handles_overlay_wider_than_base() {
+        let out = composite_png(&solid_png(100, 100), &solid_png(500, 420)).unwrap();
+        let image = image::load_from_memory(&out).unwrap().to_rgba8();
+        assert_eq!(image.dimensions(), (100, 100));
+    }
+
+    #[test]
+    fn composite_rejects_oversized_base() {
+        let err = composite_png(&solid_png(MAX_RENDER_EDGE + 1, 10), OVERLAY_BLUE_BYTES)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Unsupported base image size"), "{}", err);
+    }
+
+    #[test]
+    fn composite_rejects_16_bit_base() {
+        let base: ImageBuffer<Rgba<u16>, Vec<u16>> = ImageBuffer::new(420, 420);
+        let mut png = Vec::new();
+        DynamicImage::ImageRgba16(base)
+            .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+            .unwrap();
+
+        let err = composite_png(&png, OVERLAY_BLUE_BYTES)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Unsupported base image color type"), "{}", err);
+    }
+
+    #[test]
+    fn composite_rejects_garbage_base() {
+        assert!(composite_png(b"not a png", OVERLAY_BLUE_BYTES).is_err());
+    }
+
+    #[test]
+    fn thumbnail_fits_the_requested_edge() {
+        let composite = composite_png(&solid_png(420, 420), OVERLAY_BLUE_BYTES).unwrap();
+        for edge in [64, 128, 256] {
+            let out = thumbnail_png(&composite, edge).unwrap();
+            assert_eq!(image_dimensions(&out).unwrap(), (edge, edge));
+        }
+
+        let out = thumbnail_png(&solid_png(600, 400), 128).unwrap();
+        assert_eq!(image_dimensions(&out).unwrap(), (128, 85));
+    }
+
+    #[test]
+    fn thumbnail_rejects_out_of_range_edges() {
+        assert!(thumbnail_png(&solid_png(420, 420), MIN_THUMBNAIL_EDGE - 1).is_err());
+        assert!(thumbnail_png(&solid_png(420, 420), MAX_THUMBNAIL_EDGE + 1).is_err());
+    }
+
+    #[test]
+    fn variants_report_their_content_type() {
+        assert_eq!(RenderVariant::from_selector(0).unwrap(), RenderVariant::Raw);
+        assert_eq!(
+            RenderVariant::from_selector(2).unwrap().content_type(&[]),
+            "image/png"
+        );
+        assert_eq!(
+            RenderVariant::from_selector(3).unwrap().content_type(&[]),
+            "image/svg+xml"
+        );
+        assert!(RenderVariant::from_selector(4).is_err());
+    }
+
+    #[test]
+    fn raw_variant_reports_the_upstream_type() {
+        let raw = RenderVariant::Raw;
+        assert_eq!(raw.content_type(&solid_png(4, 4)), "image/png");
+        assert_eq!(raw.content_type(&[0xff, 0xd8, 0xff, 0xe0]), "image/jpeg");
+        assert_eq!(raw.content_type(b"<svg/>"), "image/svg+xml");
+    }
+
+    #[test]
+    fn sniffs_common_image_formats() {
+        assert_eq!(sniff_content_type(OVERLAY_BLUE_BYTES), "image/png");
+        assert_eq!(
+            sniff_content_type(&[0xff, 0xd8, 0xff, 0xdb, 0x00]),
+            "image/jpeg"
+        );
+        assert_eq!(sniff_content_type(b"GIF87a\x01\x00"), "image/gif");
+        assert_eq!(sniff_content_type(b"GIF89a\x01\x00"), "image/gif");
+        assert_eq!(
+            sniff_content_type(b"RIFF\x24\x00\x00\x00WEBPVP8 "),
+            "image/webp"
+        );
+        assert_eq!(
+            sniff_content_type(b"<svg xmlns=\"http://www.w3.org/2000/svg\"/>"),
+            "image/svg+xml"
+        );
+        assert_eq!(
+            sniff_content_type(b"\n  <?xml version=\"1.0\"?>\n<svg/>"),
+            "image/svg+xml"
+        );
+    }
+
+    #[test]
+    fn unknown_bytes_are_an_octet_stream() {
+        assert_eq!(sniff_content_type(&[]), "application/octet-stream");
+        assert_eq!(
+            sniff_content_type(b"not an image"),
+            "application/octet-stream"
+        );
+        assert_eq!(
+            sniff_content_type(b"RIFF\x24\x00\x00\x00WAVE"),
+            "application/octet-stream"
+        );
+        assert_eq!(
+            sniff_content_type(b"<?xml version=\"1.0\"?><html/>"),
+            "application/octet-stream"
+        );
+    }
+
+    #[test]
+    fn svg_embeds_both_images() {
+        let base = solid_png(600, 400);
+        let svg = svg_document(&base, OVERLAY_BLUE_BYTES).unwrap();
+        assert!(svg.contains(&format!("base64,{}\"", STANDARD.encode(&base))));
+        assert!(svg.contains(&format!("base64,{}\"", STANDARD.encode(OVERLAY_BLUE_BYTES))));
+    }
+
+    #[test]
+    fn svg_fits_overlay_to_base() {
+        let svg = svg_document(&solid_png(600, 400), OVERLAY_BLUE_BYTES).unwrap();
+        assert!(svg.contains(r#"viewBox="0 0 600 400""#));
+        assert!(svg.contains(r#"x="100" y="0" width="400" height="400""#));
+    }
+}
@@ -1,3 +1,18 @@
 mod generate_image;
 mod simple_overlay_test;
 mod test_get_data;
+mod test_id_set;
+mod test_ids;
+mod test_instances;
+mod test_legacy;
+mod test_lockup;
+mod test_lp_lookup;
+mod test_overlays;
+mod test_pause;
+mod test_position;
+mod test_registry;
+mod test_sessions;
+mod test_trait_weights;
+mod test_unbonding;
+mod test_unstake;
+mod test_vault_stats;
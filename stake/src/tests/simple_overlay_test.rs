@@ -1,4 +1,4 @@
-use crate::OVERLAY_BYTES;
+use crate::OVERLAY_BLUE_BYTES;
 use image::{imageops, DynamicImage, ImageFormat, RgbaImage};
 use std::io::Cursor;
 
@@ -30,7 +30,7 @@ fn generate_png_with_overlay() -> Result<Vec<u8>, Box<dyn std::error::Error>> {
 
     // Decode both images
     let mut base: RgbaImage = image::load_from_memory(&base_png_data)?.to_rgba8();
-    let overlay: RgbaImage = image::load_from_memory(OVERLAY_BYTES)?.to_rgba8();
+    let overlay: RgbaImage = image::load_from_memory(OVERLAY_BLUE_BYTES)?.to_rgba8();
 
     // Get dimensions for resizing if needed
     let (overlay_width, overlay_height) = overlay.dimensions();
@@ -23,7 +23,7 @@ mod tests {
         }
 
         // Load the actual overlay from the assets
-        let overlay: RgbaImage = image::load_from_memory(OVERLAY_BYTES)?.to_rgba8();
+        let overlay: RgbaImage = image::load_from_memory(OVERLAY_BLUE_BYTES)?.to_rgba8();
 
         // Composite the overlay onto the base
         imageops::overlay(&mut base, &overlay, 0, 0);
@@ -40,7 +40,7 @@ mod tests {
         let mut base: RgbaImage = image::load_from_memory(MOCK_BASE_PNG)?.to_rgba8();
 
         // Load the actual overlay from the assets
-        let overlay: RgbaImage = image::load_from_memory(OVERLAY_BYTES)?.to_rgba8();
+        let overlay: RgbaImage = image::load_from_memory(OVERLAY_BLUE_BYTES)?.to_rgba8();
 
         // Resize base to match overlay dimensions if needed
         let (overlay_width, overlay_height) = overlay.dimensions();
@@ -70,7 +70,7 @@ mod tests {
 
         // decode both images
         let mut base: RgbaImage = image::load_from_memory(base_png_data)?.to_rgba8();
-        let overlay: RgbaImage = image::load_from_memory(OVERLAY_BYTES)?.to_rgba8();
+        let overlay: RgbaImage = image::load_from_memory(OVERLAY_BLUE_BYTES)?.to_rgba8();
 
         // If the base image is too small, resize it to accommodate the overlay
         let (overlay_width, overlay_height) = overlay.dimensions();
@@ -175,7 +175,7 @@ mod tests {
     #[test]
     fn test_overlay_bytes_valid() {
         // Test that our overlay asset is valid
-        let decoded = image::load_from_memory(OVERLAY_BYTES);
+        let decoded = image::load_from_memory(OVERLAY_BLUE_BYTES);
         assert!(decoded.is_ok(), "Overlay PNG should be valid");
 
         let img = decoded.unwrap();
@@ -0,0 +1,106 @@
+use crate::id_set::IdSet;
+use metashrew_support::index_pointer::KeyValuePointer;
+use orbitals_common::memory_pointer::MemoryPointer;
+
+fn id(tx: u128) -> Vec<u8> {
+    let mut bytes = 2u128.to_le_bytes().to_vec();
+    bytes.extend_from_slice(&tx.to_le_bytes());
+    bytes
+}
+
+fn new_set(keyword: &str) -> IdSet<MemoryPointer> {
+    IdSet::new(
+        MemoryPointer::from_keyword(&format!("{}/entries", keyword)),
+        MemoryPointer::from_keyword(&format!("{}/positions", keyword)),
+    )
+}
+
+fn entries(set: &IdSet<MemoryPointer>) -> Vec<Vec<u8>> {
+    (0..set.len()).map(|i| set.get(i).to_vec()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_appends_in_order() {
+        let mut set = new_set("/insert");
+
+        set.insert(&id(1)).unwrap();
+        set.insert(&id(2)).unwrap();
+        set.insert(&id(3)).unwrap();
+
+        assert_eq!(entries(&set), vec![id(1), id(2), id(3)]);
+    }
+
+    #[test]
+    fn test_insert_ignores_duplicates() {
+        let mut set = new_set("/duplicates");
+
+        assert!(set.insert(&id(1)).unwrap());
+        assert!(!set.insert(&id(1)).unwrap());
+
+        assert_eq!(entries(&set), vec![id(1)]);
+    }
+
+    #[test]
+    fn test_remove_moves_last_entry_into_slot() {
+        let mut set = new_set("/swap-remove");
+        for tx in 1..=4 {
+            set.insert(&id(tx)).unwrap();
+        }
+
+        assert!(set.remove(&id(2)).unwrap());
+
+        assert_eq!(entries(&set), vec![id(1), id(4), id(3)]);
+        assert!(set.get(3).is_empty());
+    }
+
+    #[test]
+    fn test_remove_keeps_moved_entry_removable() {
+        let mut set = new_set("/moved");
+        for tx in 1..=3 {
+            set.insert(&id(tx)).unwrap();
+        }
+
+        set.remove(&id(1)).unwrap();
+        assert!(set.remove(&id(3)).unwrap());
+
+        assert_eq!(entries(&set), vec![id(2)]);
+    }
+
+    #[test]
+    fn test_remove_last_and_only_entries() {
+        let mut set = new_set("/last");
+        set.insert(&id(1)).unwrap();
+        set.insert(&id(2)).unwrap();
+
+        assert!(set.remove(&id(2)).unwrap());
+        assert_eq!(entries(&set), vec![id(1)]);
+
+        assert!(set.remove(&id(1)).unwrap());
+        assert_eq!(set.len(), 0);
+    }
+
+    #[test]
+    fn test_remove_missing_id_is_a_no_op() {
+        let mut set = new_set("/missing");
+        set.insert(&id(1)).unwrap();
+
+        assert!(!set.remove(&id(9)).unwrap());
+        assert_eq!(entries(&set), vec![id(1)]);
+    }
+
+    #[test]
+    fn test_removed_id_can_be_inserted_again() {
+        let mut set = new_set("/reinsert");
+        set.insert(&id(1)).unwrap();
+        set.insert(&id(2)).unwrap();
+
+        set.remove(&id(1)).unwrap();
+        set.insert(&id(1)).unwrap();
+
+        assert_eq!(entries(&set), vec![id(2), id(1)]);
+    }
+}
@@ -0,0 +1,33 @@
+use crate::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alkane_id_bytes_round_trip() {
+        let staking = Staking::default();
+
+        for id in [
+            AlkaneId { block: 0, tx: 0 },
+            BEEP_BOOP_COLLECTION,
+            AlkaneId {
+                block: u128::MAX,
+                tx: 1,
+            },
+        ] {
+            let bytes = staking.alkane_id_to_bytes(&id);
+            assert_eq!(bytes.len(), 32);
+            assert_eq!(staking.bytes_to_alkane_id(&bytes).unwrap(), id);
+        }
+    }
+
+    #[test]
+    fn test_binary_id_of_wrong_length_is_rejected() {
+        let staking = Staking::default();
+
+        assert!(staking.bytes_to_alkane_id(b"2:31064").is_err());
+        assert!(staking.bytes_to_alkane_id(&[0u8; 31]).is_err());
+        assert!(staking.bytes_to_alkane_id(&[]).is_err());
+    }
+}
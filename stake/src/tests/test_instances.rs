@@ -0,0 +1,110 @@
+use crate::*;
+use orbitals_common::memory_pointer::MemoryPointer;
+
+const OTHER_COLLECTION: AlkaneId = AlkaneId {
+    block: 2,
+    tx: 90000,
+};
+
+fn lp(tx: u128) -> AlkaneId {
+    AlkaneId { block: 2, tx }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instance_keys_differ_across_collections() {
+        let staking = Staking::default();
+
+        let beep_boop_key = staking.instance_key(&BEEP_BOOP_COLLECTION, 7);
+        let other_key = staking.instance_key(&OTHER_COLLECTION, 7);
+
+        assert_eq!(beep_boop_key.len(), 48);
+        assert_ne!(beep_boop_key, other_key);
+        assert_eq!(
+            &beep_boop_key[0..32],
+            staking.alkane_id_to_bytes(&BEEP_BOOP_COLLECTION).as_slice()
+        );
+        assert_eq!(&beep_boop_key[32..48], &7u128.to_le_bytes());
+    }
+
+    #[test]
+    fn test_same_index_in_two_collections_keeps_both_lps() {
+        let staking = Staking::default();
+        let instances = MemoryPointer::from_keyword("/instances-two-collections");
+
+        instances
+            .select(&staking.instance_key(&BEEP_BOOP_COLLECTION, 7))
+            .set(Arc::new(staking.alkane_id_to_bytes(&lp(100))));
+        instances
+            .select(&staking.instance_key(&OTHER_COLLECTION, 7))
+            .set(Arc::new(staking.alkane_id_to_bytes(&lp(200))));
+
+        assert_eq!(
+            staking.find_instance(&instances, &BEEP_BOOP_COLLECTION, 7),
+            Some(lp(100))
+        );
+        assert_eq!(
+            staking.find_instance(&instances, &OTHER_COLLECTION, 7),
+            Some(lp(200))
+        );
+        assert_eq!(
+            staking.find_instance(&instances, &OTHER_COLLECTION, 8),
+            None
+        );
+    }
+
+    #[test]
+    fn test_legacy_index_key_only_counts_for_beep_boop() {
+        let staking = Staking::default();
+        let instances = MemoryPointer::from_keyword("/instances-legacy");
+
+        instances
+            .select(&7u128.to_le_bytes().to_vec())
+            .set(Arc::new(staking.alkane_id_to_bytes(&lp(100))));
+
+        assert_eq!(
+            staking.find_instance(&instances, &BEEP_BOOP_COLLECTION, 7),
+            Some(lp(100))
+        );
+        assert_eq!(
+            staking.find_instance(&instances, &OTHER_COLLECTION, 7),
+            None
+        );
+    }
+
+    #[test]
+    fn test_index_of_another_collection_does_not_resolve() {
+        let staking = Staking::default();
+        let instances = MemoryPointer::from_keyword("/instances-no-guess");
+
+        instances
+            .select(&staking.instance_key(&OTHER_COLLECTION, 7))
+            .set(Arc::new(staking.alkane_id_to_bytes(&lp(200))));
+
+        assert_eq!(
+            staking.find_instance(&instances, &BEEP_BOOP_COLLECTION, 7),
+            None
+        );
+    }
+
+    #[test]
+    fn test_qualified_key_wins_over_legacy_key() {
+        let staking = Staking::default();
+        let instances = MemoryPointer::from_keyword("/instances-precedence");
+
+        instances
+            .select(&7u128.to_le_bytes().to_vec())
+            .set(Arc::new(staking.alkane_id_to_bytes(&lp(100))));
+        instances
+            .select(&staking.instance_key(&BEEP_BOOP_COLLECTION, 7))
+            .set(Arc::new(staking.alkane_id_to_bytes(&lp(101))));
+
+        assert_eq!(
+            staking.find_instance(&instances, &BEEP_BOOP_COLLECTION, 7),
+            Some(lp(101))
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use crate::orbital_records::OrbitalRecords;
+use crate::*;
+use orbitals_common::memory_pointer::MemoryPointer;
+
+const LEGACY_ORBITAL: AlkaneId = AlkaneId {
+    block: 2,
+    tx: 31_100,
+};
+const LEGACY_LP: AlkaneId = AlkaneId {
+    block: 2,
+    tx: 90_000,
+};
+
+/// Write what the vault stored for a stake before the eligibility cache
+/// existed: the stake height, the closed sessions and the LP mapping
+fn stake_under_old_layout(prefix: &str, stake_height: u128, total_staked_blocks: u128) {
+    let staking = Staking::default();
+    let orbital_bytes = staking.alkane_id_to_bytes(&LEGACY_ORBITAL);
+
+    MemoryPointer::from_keyword(&format!("{}/stake-height", prefix))
+        .select(&orbital_bytes)
+        .set_value(stake_height);
+    MemoryPointer::from_keyword(&format!("{}/total-staked-blocks", prefix))
+        .select(&orbital_bytes)
+        .set_value(total_staked_blocks);
+    MemoryPointer::from_keyword(&format!("{}/address-staked-pointer", prefix))
+        .select(&staking.alkane_id_to_bytes(&LEGACY_LP))
+        .set(Arc::new(orbital_bytes));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legacy_stake_reads_after_upgrade() {
+        stake_under_old_layout("/legacy-staked", 100, 40);
+        let records = OrbitalRecords::in_memory("/legacy-staked");
+
+        assert_eq!(records.resolve(&LEGACY_LP).unwrap(), LEGACY_ORBITAL);
+        assert_eq!(records.resolve(&LEGACY_ORBITAL).unwrap(), LEGACY_ORBITAL);
+        assert_eq!(records.stake_height(&LEGACY_ORBITAL), 100);
+        assert_eq!(records.total_staked_blocks(&LEGACY_ORBITAL), 40);
+        assert_eq!(records.accrued_blocks(&LEGACY_ORBITAL, 160).unwrap(), 100);
+        assert_eq!(
+            records.collection_id(&LEGACY_ORBITAL).unwrap(),
+            BEEP_BOOP_COLLECTION
+        );
+
+        // The old layout never wrote the orbital to LP mapping
+        let position = records.position(&LEGACY_ORBITAL, 7, 160, 0);
+        assert_eq!(position.lp_id, None);
+        assert_eq!(position.staked_at_block, 100);
+        assert_eq!(position.total_staked_blocks, 40);
+        assert_eq!(position.current_accrual, 60);
+    }
+
+    #[test]
+    fn test_legacy_unstaked_orbital_keeps_its_history() {
+        stake_under_old_layout("/legacy-unstaked", 0, 250);
+        let records = OrbitalRecords::in_memory("/legacy-unstaked");
+
+        assert_eq!(records.stake_height(&LEGACY_ORBITAL), 0);
+        assert_eq!(records.accrued_blocks(&LEGACY_ORBITAL, 500).unwrap(), 250);
+        assert_eq!(
+            records.collection_id(&LEGACY_ORBITAL).unwrap(),
+            BEEP_BOOP_COLLECTION
+        );
+    }
+
+    #[test]
+    fn test_cached_collection_wins_over_legacy_fallback() {
+        let staking = Staking::default();
+        let other = AlkaneId {
+            block: 2,
+            tx: 70_000,
+        };
+        stake_under_old_layout("/legacy-cached", 100, 0);
+        MemoryPointer::from_keyword("/legacy-cached/eligible-orbitals")
+            .select(&staking.alkane_id_to_bytes(&LEGACY_ORBITAL))
+            .set(Arc::new(staking.alkane_id_to_bytes(&other)));
+
+        let records = OrbitalRecords::in_memory("/legacy-cached");
+
+        assert_eq!(records.collection_id(&LEGACY_ORBITAL).unwrap(), other);
+    }
+
+    #[test]
+    fn test_never_staked_orbital_reads_zeroes() {
+        let records = OrbitalRecords::in_memory("/legacy-never-staked");
+
+        assert_eq!(records.resolve(&LEGACY_ORBITAL).unwrap(), LEGACY_ORBITAL);
+        assert_eq!(records.stake_height(&LEGACY_ORBITAL), 0);
+        assert_eq!(records.accrued_blocks(&LEGACY_ORBITAL, 500).unwrap(), 0);
+        assert!(records.collection_id(&LEGACY_ORBITAL).is_err());
+    }
+}
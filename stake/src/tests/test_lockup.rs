@@ -0,0 +1,95 @@
+use crate::*;
+
+const PERIOD_BLOCKS: u128 = 1_000;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_lock_has_no_unlock_height() {
+        let staking = Staking::default();
+
+        assert_eq!(staking.unlock_height(900_000, 0).unwrap(), 0);
+        assert_eq!(staking.unlock_height(900_000, 144).unwrap(), 900_144);
+    }
+
+    #[test]
+    fn test_unlock_height_overflow() {
+        let staking = Staking::default();
+
+        let err = staking.unlock_height(u128::MAX, 1).unwrap_err();
+        assert_eq!(err.to_string(), "Unlock height overflow");
+    }
+
+    #[test]
+    fn test_early_unstake_forfeits_penalty_share() {
+        let staking = Staking::default();
+
+        assert_eq!(
+            staking
+                .forfeited_blocks(PERIOD_BLOCKS, 100, 200, 2_500)
+                .unwrap(),
+            250
+        );
+        assert_eq!(
+            staking
+                .forfeited_blocks(PERIOD_BLOCKS, 100, 200, PENALTY_BASE)
+                .unwrap(),
+            PERIOD_BLOCKS
+        );
+        assert_eq!(staking.forfeited_blocks(3, 100, 200, 5_000).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_unstake_at_or_after_unlock_keeps_everything() {
+        let staking = Staking::default();
+
+        assert_eq!(
+            staking
+                .forfeited_blocks(PERIOD_BLOCKS, 200, 200, 2_500)
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            staking
+                .forfeited_blocks(PERIOD_BLOCKS, 201, 200, 2_500)
+                .unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_unlocked_stake_never_forfeits() {
+        let staking = Staking::default();
+
+        assert_eq!(
+            staking
+                .forfeited_blocks(PERIOD_BLOCKS, 100, 0, PENALTY_BASE)
+                .unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_zero_penalty_forfeits_nothing() {
+        let staking = Staking::default();
+
+        assert_eq!(
+            staking
+                .forfeited_blocks(PERIOD_BLOCKS, 100, 200, 0)
+                .unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_forfeited_blocks_overflow() {
+        let staking = Staking::default();
+
+        let err = staking
+            .forfeited_blocks(u128::MAX, 100, 200, 2)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Forfeited blocks overflow");
+    }
+}
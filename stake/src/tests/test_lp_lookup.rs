@@ -0,0 +1,40 @@
+use crate::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lp_lookup_for_staked_orbital() {
+        let staking = Staking::default();
+        let lp_id_bytes = staking.alkane_id_to_bytes(&AlkaneId { block: 2, tx: 4242 });
+
+        let data = staking.encode_lp_lookup(&lp_id_bytes, true);
+
+        assert_eq!(data.len(), 34);
+        assert_eq!(&data[0..32], lp_id_bytes.as_slice());
+        assert_eq!(data[32], 1);
+        assert_eq!(data[33], 1);
+    }
+
+    #[test]
+    fn test_lp_lookup_for_unstaked_orbital_keeps_its_lp() {
+        let staking = Staking::default();
+        let lp_id_bytes = staking.alkane_id_to_bytes(&AlkaneId { block: 2, tx: 4242 });
+
+        let data = staking.encode_lp_lookup(&lp_id_bytes, false);
+
+        assert_eq!(&data[0..32], lp_id_bytes.as_slice());
+        assert_eq!(data[32], 1);
+        assert_eq!(data[33], 0);
+    }
+
+    #[test]
+    fn test_lp_lookup_for_never_staked_orbital() {
+        let staking = Staking::default();
+
+        let data = staking.encode_lp_lookup(&[], false);
+
+        assert_eq!(data, [vec![0u8; 32], vec![0, 0]].concat());
+    }
+}
@@ -0,0 +1,91 @@
+use crate::*;
+
+const INDEXES: std::ops::Range<u128> = 0..64;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_weights_falls_back_to_tiers() {
+        let staking = Staking::default();
+
+        for index in INDEXES {
+            assert_eq!(staking.roll_overlay(&[0; 4], index).unwrap(), None);
+        }
+    }
+
+    #[test]
+    fn test_single_weight_always_wins() {
+        let staking = Staking::default();
+
+        for overlay in 0..4 {
+            let mut weights = [0u128; 4];
+            weights[overlay] = 7;
+            for index in INDEXES {
+                assert_eq!(
+                    staking.roll_overlay(&weights, index).unwrap(),
+                    Some(overlay)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_roll_is_fixed_per_index() {
+        let staking = Staking::default();
+        let weights = [50, 30, 15, 5];
+
+        for index in INDEXES {
+            let first = staking.roll_overlay(&weights, index).unwrap();
+            assert_eq!(staking.roll_overlay(&weights, index).unwrap(), first);
+        }
+    }
+
+    #[test]
+    fn test_zero_weight_is_never_drawn() {
+        let staking = Staking::default();
+        let weights = [1, 0, 1, 0];
+
+        for index in INDEXES {
+            let overlay = staking.roll_overlay(&weights, index).unwrap().unwrap();
+            assert!(overlay == 0 || overlay == 2);
+        }
+    }
+
+    #[test]
+    fn test_overflowing_weights_rejected() {
+        let staking = Staking::default();
+
+        let err = staking.roll_overlay(&[u128::MAX, 1, 0, 0], 1).unwrap_err();
+        assert_eq!(err.to_string(), "Overlay weights overflow");
+    }
+
+    #[test]
+    fn test_tiers_follow_accrued_blocks() {
+        let staking = Staking::default();
+
+        for (tier, (threshold, _, _)) in OVERLAY_TIERS.iter().enumerate() {
+            assert_eq!(staking.overlay_tier(*threshold), tier);
+        }
+        assert_eq!(staking.overlay_tier(u128::MAX), OVERLAY_TIERS.len() - 1);
+    }
+
+    #[test]
+    fn test_rarity_overlay_is_not_reported_as_a_tier() {
+        let staking = Staking::default();
+
+        let attributes = staking.overlay_attributes(3, None);
+        assert_eq!(attributes["Overlay"], OVERLAY_TIERS[3].1);
+        assert!(!attributes.contains_key("Overlay Tier"));
+    }
+
+    #[test]
+    fn test_staking_tier_reported_while_it_drives_the_overlay() {
+        let staking = Staking::default();
+
+        let attributes = staking.overlay_attributes(2, Some(2));
+        assert_eq!(attributes["Overlay"], OVERLAY_TIERS[2].1);
+        assert_eq!(attributes["Overlay Tier"], 2);
+    }
+}
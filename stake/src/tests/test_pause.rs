@@ -0,0 +1,70 @@
+use crate::*;
+
+const STAKE_ACTIONS: [VaultAction; 2] = [VaultAction::Stake, VaultAction::CancelUnstake];
+const UNSTAKE_ACTIONS: [VaultAction; 3] = [
+    VaultAction::Unstake,
+    VaultAction::RequestUnstake,
+    VaultAction::CompleteUnstake,
+];
+
+fn transfer(id: AlkaneId, value: u128) -> AlkaneTransfer {
+    AlkaneTransfer { id, value }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nothing_is_gated_when_unpaused() {
+        let staking = Staking::default();
+
+        for action in STAKE_ACTIONS.iter().chain(UNSTAKE_ACTIONS.iter()) {
+            assert!(staking.check_pause(*action, false, false).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_stake_pause_blocks_stake_and_cancel() {
+        let staking = Staking::default();
+
+        for action in STAKE_ACTIONS {
+            let err = staking.check_pause(action, true, false).unwrap_err();
+            assert_eq!(err.to_string(), "Staking is paused");
+        }
+    }
+
+    #[test]
+    fn test_unstake_stays_open_while_staking_is_paused() {
+        let staking = Staking::default();
+
+        for action in UNSTAKE_ACTIONS {
+            assert!(staking.check_pause(action, true, false).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_unstake_pause_blocks_every_exit() {
+        let staking = Staking::default();
+
+        for action in UNSTAKE_ACTIONS {
+            let err = staking.check_pause(action, false, true).unwrap_err();
+            assert_eq!(err.to_string(), "Unstaking is paused");
+        }
+        for action in STAKE_ACTIONS {
+            assert!(staking.check_pause(action, false, true).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_admin_token_required() {
+        let staking = Staking::default();
+        let myself = AlkaneId { block: 2, tx: 500 };
+        let other = AlkaneId { block: 2, tx: 501 };
+
+        assert!(staking.carries_admin_token(&[transfer(other, 1), transfer(myself, 1)], &myself));
+        assert!(!staking.carries_admin_token(&[transfer(other, 1)], &myself));
+        assert!(!staking.carries_admin_token(&[transfer(myself, 0)], &myself));
+        assert!(!staking.carries_admin_token(&[], &myself));
+    }
+}
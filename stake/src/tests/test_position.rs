@@ -0,0 +1,87 @@
+use crate::orbital_records::{OrbitalRecords, Position, POSITION_SIZE};
+use crate::*;
+use orbitals_common::memory_pointer::MemoryPointer;
+
+const ORBITAL: AlkaneId = AlkaneId { block: 2, tx: 42 };
+const LP: AlkaneId = AlkaneId { block: 2, tx: 4242 };
+
+fn word(data: &[u8], offset: usize) -> u128 {
+    u128::from_le_bytes(data[offset..offset + 16].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_layout() {
+        let position = Position {
+            orbital_id: ORBITAL,
+            lp_id: Some(LP),
+            index: 7,
+            staked_at_block: 900_000,
+            total_staked_blocks: 1_000,
+            current_accrual: 144,
+            pending_rewards: 5_000_000,
+        };
+
+        let data = position.encode();
+
+        assert_eq!(POSITION_SIZE, 145);
+        assert_eq!(data.len(), POSITION_SIZE);
+        assert_eq!((word(&data, 0), word(&data, 16)), (2, 42));
+        assert_eq!((word(&data, 32), word(&data, 48)), (2, 4242));
+        assert_eq!(word(&data, 64), 7);
+        assert_eq!(data[80], 1);
+        assert_eq!(word(&data, 81), 900_000);
+        assert_eq!(word(&data, 97), 1_000);
+        assert_eq!(word(&data, 113), 144);
+        assert_eq!(word(&data, 129), 5_000_000);
+    }
+
+    #[test]
+    fn test_position_of_staked_orbital() {
+        let staking = Staking::default();
+        let orbital_bytes = staking.alkane_id_to_bytes(&ORBITAL);
+        MemoryPointer::from_keyword("/position-staked/stake-height")
+            .select(&orbital_bytes)
+            .set_value(900_000u128);
+        MemoryPointer::from_keyword("/position-staked/total-staked-blocks")
+            .select(&orbital_bytes)
+            .set_value(1_000u128);
+        MemoryPointer::from_keyword("/position-staked/lp-by-orbital")
+            .select(&orbital_bytes)
+            .set(Arc::new(staking.alkane_id_to_bytes(&LP)));
+
+        let position =
+            OrbitalRecords::in_memory("/position-staked").position(&ORBITAL, 7, 900_144, 5);
+
+        assert_eq!(
+            position,
+            Position {
+                orbital_id: ORBITAL,
+                lp_id: Some(LP),
+                index: 7,
+                staked_at_block: 900_000,
+                total_staked_blocks: 1_000,
+                current_accrual: 144,
+                pending_rewards: 5,
+            }
+        );
+    }
+
+    #[test]
+    fn test_position_of_never_staked_orbital_is_zeroed() {
+        let records = OrbitalRecords::in_memory("/position-never-staked");
+
+        let orbital_id = records.resolve(&ORBITAL).unwrap();
+        let data = records.position(&orbital_id, 0, 900_144, 0).encode();
+
+        assert_eq!(data.len(), POSITION_SIZE);
+        assert_eq!(
+            &data[0..32],
+            Staking::default().alkane_id_to_bytes(&ORBITAL)
+        );
+        assert!(data[32..].iter().all(|byte| *byte == 0));
+    }
+}
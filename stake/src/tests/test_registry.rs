@@ -0,0 +1,35 @@
+use crate::*;
+use orbitals_common::memory_pointer::MemoryPointer;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_registry_defaults_to_beep_boop() {
+        let staking = Staking::default();
+        let collections = MemoryPointer::from_keyword("/eligible-collections-empty");
+
+        assert_eq!(
+            staking.first_collection_id(&collections).unwrap(),
+            BEEP_BOOP_COLLECTION
+        );
+    }
+
+    #[test]
+    fn test_first_registered_collection_is_the_default() {
+        let staking = Staking::default();
+        let mut collections = MemoryPointer::from_keyword("/eligible-collections-seeded");
+        let other = AlkaneId {
+            block: 2,
+            tx: 70_000,
+        };
+
+        collections
+            .select(&0u128.to_le_bytes().to_vec())
+            .set(Arc::new(staking.alkane_id_to_bytes(&other)));
+        collections.set_value(1u128);
+
+        assert_eq!(staking.first_collection_id(&collections).unwrap(), other);
+    }
+}
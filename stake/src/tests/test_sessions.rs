@@ -0,0 +1,63 @@
+use crate::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_range_within_bounds() {
+        let staking = Staking::default();
+
+        assert_eq!(staking.page_range(0, 10, 25), 0..10);
+        assert_eq!(staking.page_range(10, 10, 25), 10..20);
+    }
+
+    #[test]
+    fn test_page_range_stops_at_count() {
+        let staking = Staking::default();
+
+        assert_eq!(staking.page_range(20, 10, 25), 20..25);
+        assert_eq!(staking.page_range(0, 10, 0), 0..0);
+    }
+
+    #[test]
+    fn test_page_range_past_the_end_is_empty() {
+        let staking = Staking::default();
+
+        assert!(staking.page_range(30, 10, 25).is_empty());
+        assert!(staking.page_range(u128::MAX, u128::MAX, 25).is_empty());
+    }
+
+    #[test]
+    fn test_page_range_caps_limit_at_max_page_size() {
+        let staking = Staking::default();
+
+        assert_eq!(staking.page_range(0, 1_000, 500), 0..MAX_PAGE_SIZE);
+        assert_eq!(staking.page_range(5, u128::MAX, 500), 5..5 + MAX_PAGE_SIZE);
+    }
+
+    #[test]
+    fn test_stake_session_layout() {
+        let staking = Staking::default();
+        let lp_id = AlkaneId { block: 2, tx: 77 };
+
+        let session = staking.encode_stake_session(840_000, 840_144, &lp_id);
+
+        assert_eq!(session.len(), STAKE_SESSION_SIZE);
+        assert_eq!(&session[0..16], &840_000u128.to_le_bytes());
+        assert_eq!(&session[16..32], &840_144u128.to_le_bytes());
+        assert_eq!(
+            &session[32..64],
+            staking.alkane_id_to_bytes(&lp_id).as_slice()
+        );
+    }
+
+    #[test]
+    fn test_open_stake_session_has_zero_end() {
+        let staking = Staking::default();
+
+        let session = staking.encode_stake_session(840_000, 0, &AlkaneId { block: 2, tx: 1 });
+
+        assert_eq!(&session[16..32], &[0u8; 16]);
+    }
+}
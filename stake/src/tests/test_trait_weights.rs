@@ -0,0 +1,132 @@
+use crate::*;
+use orbitals_common::memory_pointer::MemoryPointer;
+
+fn trait_key(trait_type: &str, value: &str) -> Vec<u8> {
+    let mut key = trait_type.as_bytes().to_vec();
+    key.push(0);
+    key.extend_from_slice(value.as_bytes());
+    key
+}
+
+fn trait_weights(keyword: &str, weights: &[(Vec<u8>, u128)]) -> MemoryPointer {
+    let trait_weights = MemoryPointer::from_keyword(keyword);
+    for (trait_key, weight) in weights {
+        trait_weights.select(trait_key).set_value(*weight);
+    }
+    trait_weights
+}
+
+fn traits(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(trait_type, value)| (trait_type.to_string(), value.to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_traits_from_object() {
+        let staking = Staking::default();
+
+        let parsed = staking.parse_traits(br#"{"Background":"Blue","Eyes":3}"#);
+
+        assert_eq!(parsed, traits(&[("Background", "Blue"), ("Eyes", "3")]));
+    }
+
+    #[test]
+    fn test_parse_traits_from_list() {
+        let staking = Staking::default();
+
+        let parsed = staking.parse_traits(
+            br#"[{"trait_type":"Background","value":"Blue"},{"value":"no type"},{"trait_type":"Eyes","value":3}]"#,
+        );
+
+        assert_eq!(parsed, traits(&[("Background", "Blue"), ("Eyes", "3")]));
+    }
+
+    #[test]
+    fn test_parse_traits_from_malformed_json() {
+        let staking = Staking::default();
+
+        assert!(staking.parse_traits(b"not json").is_empty());
+        assert!(staking.parse_traits(b"42").is_empty());
+    }
+
+    #[test]
+    fn test_unset_traits_count_as_1x() {
+        let staking = Staking::default();
+
+        let multiplier = staking
+            .weigh_traits(
+                &trait_weights("/trait-weights-unset", &[]),
+                &traits(&[("Background", "Blue")]),
+            )
+            .unwrap();
+
+        assert_eq!(multiplier, TRAIT_WEIGHT_BASE);
+    }
+
+    #[test]
+    fn test_weights_multiply() {
+        let staking = Staking::default();
+        let weights = trait_weights(
+            "/trait-weights-multiply",
+            &[
+                (trait_key("Background", "Gold"), 20_000),
+                (trait_key("Eyes", "Laser"), 15_000),
+            ],
+        );
+
+        let multiplier = staking
+            .weigh_traits(
+                &weights,
+                &traits(&[
+                    ("Background", "Gold"),
+                    ("Eyes", "Laser"),
+                    ("Mouth", "Smile"),
+                ]),
+            )
+            .unwrap();
+
+        // 2x * 1.5x * 1x
+        assert_eq!(multiplier, 30_000);
+    }
+
+    #[test]
+    fn test_zero_weight_is_not_unset() {
+        let staking = Staking::default();
+        let weights = trait_weights(
+            "/trait-weights-zero",
+            &[(trait_key("Background", "Void"), 0)],
+        );
+
+        let multiplier = staking
+            .weigh_traits(
+                &weights,
+                &traits(&[("Background", "Void"), ("Eyes", "Laser")]),
+            )
+            .unwrap();
+
+        assert_eq!(multiplier, 0);
+    }
+
+    #[test]
+    fn test_weight_overflow_is_an_error() {
+        let staking = Staking::default();
+
+        let weights = trait_weights(
+            "/trait-weights-overflow",
+            &[
+                (trait_key("A", "1"), u128::MAX),
+                (trait_key("B", "2"), u128::MAX),
+            ],
+        );
+
+        let result = staking.weigh_traits(&weights, &traits(&[("A", "1"), ("B", "2")]));
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,113 @@
+use crate::*;
+use orbitals_common::memory_pointer::MemoryPointer;
+
+const ORBITAL: AlkaneId = AlkaneId { block: 2, tx: 42 };
+const UNBONDING_BLOCKS: u128 = 144;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_not_unbonding_rejected() {
+        let staking = Staking::default();
+
+        let err = staking.check_unbonding(&ORBITAL, 0).unwrap_err();
+        assert_eq!(err.to_string(), "Orbital 2:42 is not unbonding");
+        assert_eq!(staking.check_unbonding(&ORBITAL, 900_000).unwrap(), 900_000);
+    }
+
+    #[test]
+    fn test_ready_height() {
+        let staking = Staking::default();
+
+        assert_eq!(staking.unbonding_ready_height(0, UNBONDING_BLOCKS), 0);
+        assert_eq!(
+            staking.unbonding_ready_height(900_000, UNBONDING_BLOCKS),
+            900_144
+        );
+        assert_eq!(
+            staking.unbonding_ready_height(u128::MAX, UNBONDING_BLOCKS),
+            u128::MAX
+        );
+    }
+
+    #[test]
+    fn test_complete_before_period_rejected() {
+        let staking = Staking::default();
+
+        let err = staking
+            .ensure_unbonded(&ORBITAL, 900_000, UNBONDING_BLOCKS, 900_143)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Orbital 2:42 is unbonding until block 900144"
+        );
+    }
+
+    #[test]
+    fn test_complete_once_period_is_over() {
+        let staking = Staking::default();
+
+        assert!(staking
+            .ensure_unbonded(&ORBITAL, 900_000, UNBONDING_BLOCKS, 900_144)
+            .is_ok());
+        assert!(staking
+            .ensure_unbonded(&ORBITAL, 900_000, UNBONDING_BLOCKS, 1_000_000)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_complete_without_request_rejected() {
+        let staking = Staking::default();
+
+        let err = staking
+            .ensure_unbonded(&ORBITAL, 0, UNBONDING_BLOCKS, 1_000_000)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Orbital 2:42 is not unbonding");
+    }
+
+    #[test]
+    fn test_period_shortened_after_request() {
+        let staking = Staking::default();
+
+        // the period in force when completing applies, not the one at request time
+        assert!(staking
+            .ensure_unbonded(&ORBITAL, 900_000, 0, 900_000)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_cancel_restores_the_lock_held_at_request() {
+        let staking = Staking::default();
+        let orbital_id_bytes = staking.alkane_id_to_bytes(&ORBITAL);
+        let unlock_heights = MemoryPointer::from_keyword("/unlock-height-cancel");
+        let requests = MemoryPointer::from_keyword("/unbonding-request-cancel");
+
+        // Staked at 900_000 with a 5_000 block lock
+        let unlock_height = staking.unlock_height(900_000, 5_000).unwrap();
+        unlock_heights
+            .select(&orbital_id_bytes)
+            .set_value(unlock_height);
+
+        // RequestUnstake holds the lock, then releasing the orbital clears it
+        staking.hold_unbonding(&requests, &ORBITAL, unlock_height, 1_000);
+        unlock_heights.select(&orbital_id_bytes).set_value(0u128);
+
+        // CancelUnstake puts the held lock back
+        let (restored_unlock_height, credited_blocks) = staking.take_unbonding(&requests, &ORBITAL);
+        assert_eq!(restored_unlock_height, 905_000);
+        assert_eq!(credited_blocks, 1_000);
+
+        // The request is consumed, so it cannot be restored twice
+        assert_eq!(staking.take_unbonding(&requests, &ORBITAL), (0, 0));
+    }
+
+    #[test]
+    fn test_request_without_held_lock_restores_no_lock() {
+        let staking = Staking::default();
+        let requests = MemoryPointer::from_keyword("/unbonding-request-legacy");
+
+        assert_eq!(staking.take_unbonding(&requests, &ORBITAL), (0, 0));
+    }
+}
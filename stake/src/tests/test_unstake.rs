@@ -0,0 +1,97 @@
+use crate::*;
+use orbitals_common::memory_pointer::MemoryPointer;
+
+/// LPs 2:100..2:199 were minted for orbitals 2:0..2:99, anything else is unknown
+fn staked_ids(keyword: &str) -> MemoryPointer {
+    let staking = Staking::default();
+    let staked_ids = MemoryPointer::from_keyword(keyword);
+
+    for tx in 100..200u128 {
+        staked_ids
+            .select(&staking.alkane_id_to_bytes(&AlkaneId { block: 2, tx }))
+            .set(Arc::new(staking.alkane_id_to_bytes(&AlkaneId {
+                block: 2,
+                tx: tx - 100,
+            })));
+    }
+
+    staked_ids
+}
+
+fn lp(tx: u128, value: u128) -> AlkaneTransfer {
+    AlkaneTransfer {
+        id: AlkaneId { block: 2, tx },
+        value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_resolves_every_lp_in_order() {
+        let staking = Staking::default();
+        let lps = vec![lp(105, 1), lp(101, 1), lp(150, 1)];
+
+        let orbitals = staking
+            .map_lp_orbitals(&staked_ids("/address-staked-pointer-1"), &lps)
+            .unwrap();
+
+        assert_eq!(
+            orbitals,
+            vec![
+                AlkaneId { block: 2, tx: 5 },
+                AlkaneId { block: 2, tx: 1 },
+                AlkaneId { block: 2, tx: 50 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_batch_fails_whole_call_on_unknown_lp() {
+        let staking = Staking::default();
+        let lps = vec![lp(105, 1), lp(101, 1), lp(7, 1)];
+
+        let err = staking
+            .map_lp_orbitals(&staked_ids("/address-staked-pointer-2"), &lps)
+            .unwrap_err();
+
+        assert!(err.to_string().contains("not found"));
+    }
+
+    #[test]
+    fn test_batch_rejects_partial_lp_amounts() {
+        let staking = Staking::default();
+        let lps = vec![lp(105, 1), lp(101, 2)];
+
+        let err = staking
+            .map_lp_orbitals(&staked_ids("/address-staked-pointer-3"), &lps)
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "LP token amount must be 1");
+    }
+
+    #[test]
+    fn test_batch_rejects_duplicate_lps() {
+        let staking = Staking::default();
+        let lps = vec![lp(105, 1), lp(101, 1), lp(105, 1)];
+
+        let err = staking
+            .map_lp_orbitals(&staked_ids("/address-staked-pointer-4"), &lps)
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "LP token 2:105 sent more than once");
+    }
+
+    #[test]
+    fn test_batch_requires_an_lp() {
+        let staking = Staking::default();
+
+        let err = staking
+            .map_lp_orbitals(&staked_ids("/address-staked-pointer-5"), &[])
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "Must send at least 1 LP token");
+    }
+}
@@ -0,0 +1,37 @@
+use crate::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_open_blocks_sum_each_session() {
+        let staking = Staking::default();
+
+        // staked at 100, 150 and 190, now at 200: 100 + 50 + 10 blocks
+        let open_blocks = staking.open_staked_blocks(3, 200, 100 + 150 + 190).unwrap();
+
+        assert_eq!(open_blocks, 160);
+    }
+
+    #[test]
+    fn test_open_blocks_empty_vault() {
+        let staking = Staking::default();
+
+        assert_eq!(staking.open_staked_blocks(0, 200, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_open_blocks_at_stake_height() {
+        let staking = Staking::default();
+
+        assert_eq!(staking.open_staked_blocks(2, 200, 400).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_open_blocks_overflow() {
+        let staking = Staking::default();
+
+        assert!(staking.open_staked_blocks(u128::MAX, 2, 0).is_err());
+    }
+}